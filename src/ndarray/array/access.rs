@@ -1,55 +1,150 @@
-use std::{
-    borrow::Cow,
-    ops::{Index, Range},
-};
+use std::ops::{Index, IndexMut};
 
-use crate::Array;
+use super::{is_broadcast, offset_of, remove_axis, slice_layout};
+use crate::{
+    ndarray::error::{check_axis, check_rank, unwrap_shape},
+    Array, ArrayViewMut, AxisSlice, ShapeError,
+};
 
 impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
-    pub fn slice(&'a self, slice: &[Range<usize>; D]) -> Array<'a, T, D> {
-        let mut shape = self.shape.clone();
-        let strides = self.strides.clone();
-        let mut idx_maps = self.idx_maps.clone();
-
-        slice.iter().enumerate().for_each(|(axis, range)| {
-            if range.end > self.shape[axis] {
-                panic!(
-                    "Range: [{},{}) is out of bounds for axis: {}",
-                    range.start, range.end, axis
-                )
-            }
-        });
-
-        for axis in 0..D {
-            idx_maps[axis].append_b((slice[axis].start) as isize);
-            shape[axis] = slice[axis].end - slice[axis].start;
-        }
+    pub fn slice<S: Clone + Into<AxisSlice>>(&'a self, slice: &[S; D]) -> Array<'a, T, D> {
+        unwrap_shape(self.try_slice(slice))
+    }
 
-        Array {
-            vec: Cow::from(&*self.vec),
+    pub fn try_slice<S: Clone + Into<AxisSlice>>(
+        &'a self,
+        slice: &[S; D],
+    ) -> Result<Array<'a, T, D>, ShapeError> {
+        let mut shape = self.shape;
+        let mut idx_maps = self.idx_maps;
+        slice_layout(&mut shape, &mut idx_maps, slice)?;
+
+        Ok(Array {
+            vec: self.vec.view(),
+            shape,
+            strides: self.strides,
+            idx_maps,
+            offset: self.offset,
+        })
+    }
+
+    pub fn slice_mut<S: Clone + Into<AxisSlice>>(
+        &mut self,
+        slice: &[S; D],
+    ) -> ArrayViewMut<'_, T, D> {
+        unwrap_shape(self.try_slice_mut(slice))
+    }
+
+    pub fn try_slice_mut<S: Clone + Into<AxisSlice>>(
+        &mut self,
+        slice: &[S; D],
+    ) -> Result<ArrayViewMut<'_, T, D>, ShapeError> {
+        let mut shape = self.shape;
+        let mut idx_maps = self.idx_maps;
+        slice_layout(&mut shape, &mut idx_maps, slice)?;
+
+        self.unbroadcast();
+
+        Ok(ArrayViewMut {
+            vec: self.vec.to_mut(),
             shape,
-            strides,
+            strides: self.strides,
             idx_maps,
+            offset: self.offset,
+        })
+    }
+
+    pub fn view(&self) -> Array<'_, T, D> {
+        Array {
+            vec: self.vec.view(),
+            shape: self.shape,
+            strides: self.strides,
+            idx_maps: self.idx_maps,
+            offset: self.offset,
         }
     }
 
-    pub fn get(&self, indices: [usize; D]) -> Option<&T> {
-        if indices
-            .iter()
-            .enumerate()
-            .any(|(axis, idx)| *idx >= self.shape[axis])
-        {
-            return None;
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, D> {
+        self.unbroadcast();
+
+        ArrayViewMut {
+            vec: self.vec.to_mut(),
+            shape: self.shape,
+            strides: self.strides,
+            idx_maps: self.idx_maps,
+            offset: self.offset,
         }
+    }
+
+    pub fn index_axis<const R: usize>(&self, axis: usize, index: usize) -> Array<'_, T, R> {
+        unwrap_shape(self.try_index_axis(axis, index))
+    }
+
+    pub fn try_index_axis<const R: usize>(
+        &self,
+        axis: usize,
+        index: usize,
+    ) -> Result<Array<'_, T, R>, ShapeError> {
+        check_rank(R + 1, D)?;
+        check_axis(axis, D)?;
 
-        let index = indices
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (axis, axis_index)| {
-                acc + self.idx_maps[axis].map(*axis_index) * self.strides[axis]
+        if index >= self.shape[axis] {
+            return Err(ShapeError::RangeOutOfBounds {
+                axis,
+                start: index,
+                end: index + 1,
+                len: self.shape[axis],
             });
+        }
+
+        Ok(Array {
+            vec: self.vec.view(),
+            shape: remove_axis(&self.shape, axis),
+            strides: remove_axis(&self.strides, axis),
+            idx_maps: remove_axis(&self.idx_maps, axis),
+            offset: self.offset + self.idx_maps[axis].map(index) * self.strides[axis],
+        })
+    }
 
-        self.vec.get(index)
+    pub(crate) fn buffer_index(&self, indices: &[usize; D]) -> Option<usize> {
+        offset_of(
+            self.offset,
+            &self.shape,
+            &self.strides,
+            &self.idx_maps,
+            indices,
+        )
+    }
+
+    pub fn get(&self, indices: [usize; D]) -> Option<&T> {
+        self.buffer_index(&indices)
+            .and_then(|index| self.vec.get(index))
+    }
+
+    // A borrowed array is copied into its own buffer before the first write,
+    // use `slice_mut` or `view_mut` on the owner to write through instead.
+    pub fn get_mut(&mut self, indices: [usize; D]) -> Option<&mut T> {
+        self.unbroadcast();
+
+        let index = self.buffer_index(&indices)?;
+
+        self.vec.to_mut().get_mut(index)
+    }
+
+    fn unbroadcast(&mut self) {
+        if is_broadcast(&self.shape, &self.strides) {
+            *self = Array::init(self.flat().cloned().collect(), self.shape);
+        }
+    }
+}
+
+impl<'a, T: Clone> Array<'a, T, 2> {
+    pub fn row(&self, index: usize) -> Array<'_, T, 1> {
+        self.index_axis(0, index)
+    }
+
+    pub fn column(&self, index: usize) -> Array<'_, T, 1> {
+        self.index_axis(1, index)
     }
 }
 
@@ -57,28 +152,26 @@ impl<'a, T: Clone, const D: usize> Index<[usize; D]> for Array<'a, T, D> {
     type Output = T;
 
     fn index(&self, indices: [usize; D]) -> &Self::Output {
-        if indices
-            .iter()
-            .enumerate()
-            .any(|(axis, idx)| *idx >= self.shape[axis])
-        {
-            panic!("Index out of bound");
+        match self.get(indices) {
+            Some(value) => value,
+            None => panic!("Index out of bound"),
         }
+    }
+}
 
-        let index = indices
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (axis, axis_index)| {
-                acc + self.idx_maps[axis].map(*axis_index) * self.strides[axis]
-            });
-
-        &self.vec[index]
+impl<'a, T: Clone, const D: usize> IndexMut<[usize; D]> for Array<'a, T, D> {
+    fn index_mut(&mut self, indices: [usize; D]) -> &mut Self::Output {
+        match self.get_mut(indices) {
+            Some(value) => value,
+            None => panic!("Index out of bound"),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s;
 
     #[test]
     fn index_array() {
@@ -128,4 +221,211 @@ mod tests {
             vec![11, 10, 7, 6]
         );
     }
+
+    #[test]
+    fn index_mut_array() {
+        let mut array = Array::zeros([2, 3]);
+
+        array[[0, 1]] = 1;
+        array[[1, 2]] = 2;
+        *array.get_mut([1, 0]).unwrap() = 3;
+
+        assert!(array.get_mut([2, 0]).is_none());
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 0, 3, 0, 2]
+        );
+    }
+
+    #[test]
+    fn slicing_mut() {
+        // 2-D array:
+        // 1  2  3  4
+        // 5  6  7  8
+        // 9  10 11 12
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], [3, 4]);
+
+        // patch the bottom right 2x2 block
+        // 1  2  3  4
+        // 5  6  0  0
+        // 9  10 0  0
+        let mut block = array.slice_mut(&[1..3, 2..4]);
+        block.fill(0);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 5, 6, 0, 0, 9, 10, 0, 0]
+        );
+    }
+
+    #[test]
+    fn nested_slicing_mut() {
+        let mut array = Array::arange(0..6).into_shape([2, 3]);
+
+        let mut view = array.view_mut();
+        let mut slice = view.slice_mut(&[1..2, 1..3]);
+        slice[[0, 1]] = 20;
+
+        assert_eq!(view[[1, 2]], 20);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 4, 20]
+        );
+    }
+
+    #[test]
+    fn try_slice() {
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        assert!(array.try_slice(&[0..2, 1..3]).is_ok());
+        assert_eq!(
+            array.try_slice(&[0..2, 1..4]).err(),
+            Some(ShapeError::RangeOutOfBounds {
+                axis: 1,
+                start: 1,
+                end: 4,
+                len: 3
+            })
+        );
+    }
+
+    #[test]
+    fn stepped_slicing() {
+        // 2-D array:
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9  10 11
+        // 12 13 14 15
+        let array = Array::arange(0..16).into_shape([4, 4]);
+
+        // every second row and every second column starting from the second one:
+        // 1  3
+        // 9  11
+        let slice = array.slice(&s![..;2, 1..;2]);
+
+        assert_eq!(
+            slice.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 3, 9, 11]
+        );
+    }
+
+    #[test]
+    fn reversed_slicing() {
+        // 2-D array:
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9  10 11
+        let array = Array::arange(0..12).into_shape([3, 4]);
+
+        // rows in reverse, every second column of 0..3 starting from its end:
+        // 10 8
+        // 6  4
+        // 2  0
+        let slice = array.slice(&s![..;-1, ..3;-2]);
+
+        assert_eq!(slice.shape(), &[3, 2]);
+        assert_eq!(
+            slice.flat().copied().collect::<Vec<usize>>(),
+            vec![10, 8, 6, 4, 2, 0]
+        );
+    }
+
+    #[test]
+    fn fixed_index_slicing() {
+        // 2-D array:
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9  10 11
+        let array = Array::arange(0..12).into_shape([3, 4]);
+
+        let slice = array.slice(&s![1, 2..]);
+
+        assert_eq!(slice.shape(), &[1, 2]);
+        assert_eq!(slice.flat().copied().collect::<Vec<usize>>(), vec![6, 7]);
+        assert_eq!(
+            array.try_slice(&s![.., ..;0]).err(),
+            Some(ShapeError::ZeroStep { axis: 1 })
+        );
+    }
+
+    #[test]
+    fn stepped_slicing_mut() {
+        let mut array = Array::zeros([2, 4]);
+
+        array.slice_mut(&s![.., ..;-2]).fill(1);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 0, 1, 0, 1, 0, 1]
+        );
+    }
+
+    #[test]
+    fn index_axis() {
+        // 3-D array:
+        // 0 1 2    6 7  8
+        // 3 4 5    9 10 11
+        let array = Array::arange(0..12).into_shape([2, 2, 3]);
+
+        // take the second row of both matrices:
+        // 3 4  5
+        // 9 10 11
+        let matrix = array.index_axis::<2>(1, 1);
+
+        assert_eq!(matrix.shape(), &[2, 3]);
+        assert_eq!(
+            matrix.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 4, 5, 9, 10, 11]
+        );
+
+        let vector = matrix.index_axis::<1>(1, 2);
+        assert_eq!(vector.flat().copied().collect::<Vec<usize>>(), vec![5, 11]);
+
+        assert_eq!(
+            array.try_index_axis::<1>(0, 0).err(),
+            Some(ShapeError::RankMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            array.try_index_axis::<2>(2, 3).err(),
+            Some(ShapeError::RangeOutOfBounds {
+                axis: 2,
+                start: 3,
+                end: 4,
+                len: 3
+            })
+        );
+    }
+
+    #[test]
+    fn row_and_column() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(
+            array.row(1).flat().copied().collect::<Vec<usize>>(),
+            vec![4, 5, 6]
+        );
+        assert_eq!(
+            array.column(2).flat().copied().collect::<Vec<usize>>(),
+            vec![3, 6]
+        );
+        assert_eq!(
+            array.t().row(2).flat().copied().collect::<Vec<usize>>(),
+            vec![3, 6]
+        );
+        assert_eq!(
+            array
+                .flip(1)
+                .column(0)
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![3, 6]
+        );
+    }
 }
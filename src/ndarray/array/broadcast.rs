@@ -0,0 +1,154 @@
+use std::convert::TryInto;
+
+use super::{broadcast_layout, IdxMap};
+use crate::{ndarray::error::unwrap_shape, Array, ArrayViewMut, ShapeError, Zip};
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    pub fn broadcast<const S: usize>(&self, shape: [usize; S]) -> Array<'_, T, S> {
+        unwrap_shape(self.try_broadcast(shape))
+    }
+
+    pub fn try_broadcast<const S: usize>(
+        &self,
+        shape: [usize; S],
+    ) -> Result<Array<'_, T, S>, ShapeError> {
+        let mut strides = [0; S];
+        let mut idx_maps = [IdxMap::init(); S];
+        let offset = broadcast_layout(
+            self.offset,
+            &self.shape,
+            &self.strides,
+            &self.idx_maps,
+            &shape,
+            &mut strides,
+            &mut idx_maps,
+        )?;
+
+        Ok(Array {
+            vec: self.vec.view(),
+            shape,
+            strides,
+            idx_maps,
+            offset,
+        })
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ArrayViewMut<'a, T, D> {
+    // Updates every element with the matching element of `rhs`, broadcast to
+    // the shape of the view.
+    pub(crate) fn broadcast_zip_mut<U: Clone, F, const R: usize>(
+        &mut self,
+        rhs: &Array<'_, U, R>,
+        f: F,
+    ) -> Result<(), ShapeError>
+    where
+        F: FnMut(&mut T, &U),
+    {
+        let rhs = rhs.try_broadcast(self.shape)?;
+
+        Zip::init(self).and(&rhs).for_each(f);
+
+        Ok(())
+    }
+}
+
+/// A right hand side of rank `R` never broadcasts into a result of rank `D`
+/// when `R > D`. Operators and other panicking functions evaluate `CHECK`, so
+/// such a combination fails to compile instead of panicking at runtime:
+///
+/// ```compile_fail
+/// use nd_array::Array;
+///
+/// let row = Array::init(vec![1, 2, 3], [3]);
+/// let matrix = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+///
+/// let diff = &row - &matrix;
+/// ```
+///
+/// Broadcasting the left hand side first compiles:
+///
+/// ```
+/// use nd_array::Array;
+///
+/// let row = Array::init(vec![1, 2, 3], [3]);
+/// let matrix = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+///
+/// let diff = &row.broadcast([2, 3]) - &matrix;
+/// assert_eq!(diff[[1, 2]], -3);
+/// ```
+pub(crate) struct RankFits<const D: usize, const R: usize>;
+
+impl<const D: usize, const R: usize> RankFits<D, R> {
+    pub(crate) const CHECK: () = assert!(
+        R <= D,
+        "the right hand side has more axes than the left hand side, broadcast the left hand side first"
+    );
+}
+
+// A broadcast shape as the shape of an array of rank `D`. The rank of the
+// result is fixed by the left hand side, so it can not grow to fit the shape.
+pub(crate) fn fixed_rank<const D: usize>(shape: Vec<usize>) -> Result<[usize; D], ShapeError> {
+    shape
+        .try_into()
+        .map_err(|shape| ShapeError::BroadcastRankMismatch { rank: D, shape })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn broadcast() {
+        // row vector repeated down three rows:
+        // 0 1 2 3
+        // 0 1 2 3
+        // 0 1 2 3
+        let row = Array::arange(0..4);
+        let matrix = row.broadcast([3, 4]);
+
+        assert_eq!(matrix.shape(), &[3, 4]);
+        assert_eq!(matrix.strides(), &[0, 1]);
+        assert_eq!(matrix[[2, 3]], 3);
+
+        // column vector of a reversed view repeated across four columns:
+        // 2 2 2 2
+        // 1 1 1 1
+        // 0 0 0 0
+        let column = Array::arange(0..3).into_shape([3, 1]);
+        let reversed = column.slice(&s![..;-1, ..]);
+        assert_eq!(
+            reversed
+                .broadcast([3, 4])
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![2, 2, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0]
+        );
+
+        assert_eq!(
+            row.try_broadcast([4, 3]).map(|_| ()),
+            Err(ShapeError::IncompatibleShapes {
+                left: vec![4],
+                right: vec![4, 3]
+            })
+        );
+    }
+
+    #[test]
+    fn write_to_broadcast() {
+        let row = Array::arange(0..2);
+        let mut matrix = row.broadcast([2, 2]);
+
+        // the broadcast axis gets its own elements before the first write
+        matrix.view_mut().fill(0);
+        matrix[[0, 1]] = 1;
+
+        assert_eq!(
+            matrix.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 0, 0]
+        );
+        assert_eq!(row.flat().copied().collect::<Vec<usize>>(), vec![0, 1]);
+    }
+}
@@ -1,7 +1,4 @@
-use std::{
-    borrow::Cow,
-    ops::{Add, Div, Mul, Sub},
-};
+use std::ops::{Add, Div, Mul, Sub};
 
 use num_traits::{FromPrimitive, One, Zero};
 
@@ -13,17 +10,8 @@ impl<'a, T: Clone + Ord, const D: usize> Array<'a, T, D> {
     }
 
     pub fn arg_max(&self) -> Vec<usize> {
-        let mut positions = vec![];
-
-        if let Some(max) = self.max() {
-            for (index, value) in self.flat().enumerate() {
-                if value == &max {
-                    positions.push(index)
-                }
-            }
-        }
-
-        positions
+        self.max()
+            .map_or_else(Vec::new, |max| positions_of(self.flat(), &max))
     }
 
     pub fn max_across(&self, axis: usize) -> Vec<Option<T>> {
@@ -32,7 +20,7 @@ impl<'a, T: Clone + Ord, const D: usize> Array<'a, T, D> {
 
     pub fn arg_max_across(&self, axis: usize) -> Vec<Option<usize>> {
         self.axis_view(axis)
-            .map(|view| view.arg_max().get(0).copied())
+            .map(|view| view.arg_max().first().copied())
             .collect()
     }
 
@@ -41,17 +29,8 @@ impl<'a, T: Clone + Ord, const D: usize> Array<'a, T, D> {
     }
 
     pub fn arg_min(&self) -> Vec<usize> {
-        let mut positions = vec![];
-
-        if let Some(min) = self.min() {
-            for (index, value) in self.flat().enumerate() {
-                if value == &min {
-                    positions.push(index)
-                }
-            }
-        }
-
-        positions
+        self.min()
+            .map_or_else(Vec::new, |min| positions_of(self.flat(), &min))
     }
 
     pub fn min_across(&self, axis: usize) -> Vec<Option<T>> {
@@ -60,27 +39,12 @@ impl<'a, T: Clone + Ord, const D: usize> Array<'a, T, D> {
 
     pub fn arg_min_across(&self, axis: usize) -> Vec<Option<usize>> {
         self.axis_view(axis)
-            .map(|view| view.arg_min().get(0).copied())
+            .map(|view| view.arg_min().first().copied())
             .collect()
     }
 
     pub fn clip(&self, min: &T, max: &T) -> Array<'a, T, D> {
-        let vec: Vec<T> = self
-            .vec
-            .iter()
-            .map(|val| val.clamp(min, max).clone())
-            .collect();
-
-        let shape = self.shape.clone();
-        let strides = self.strides.clone();
-        let idx_maps = self.idx_maps.clone();
-
-        Array {
-            vec: Cow::from(vec),
-            shape,
-            strides,
-            idx_maps,
-        }
+        self.map(|val| val.clamp(min, max).clone())
     }
 }
 
@@ -102,7 +66,7 @@ where
     T: Clone + Add<Output = T> + Zero,
 {
     pub fn sum(&self) -> T {
-        self.flat().fold(T::zero(), |acc, val| acc + val.clone())
+        sum_of(self.flat())
     }
 
     pub fn sum_across(&self, axis: usize) -> Vec<T> {
@@ -115,7 +79,7 @@ where
     T: Clone + Mul<Output = T> + One,
 {
     pub fn prod(&self) -> T {
-        self.flat().fold(T::one(), |acc, val| acc * val.clone())
+        prod_of(self.flat())
     }
 
     pub fn prod_across(&self, axis: usize) -> Vec<T> {
@@ -128,7 +92,7 @@ where
     T: Clone + Add<Output = T> + FromPrimitive + Div<T, Output = T> + Zero,
 {
     pub fn mean(&self) -> T {
-        self.sum() / T::from_usize(self.shape().iter().product()).unwrap()
+        mean_of(self.flat(), self.len())
     }
 
     pub fn mean_across(&self, axis: usize) -> Vec<T> {
@@ -141,11 +105,7 @@ where
     T: Clone + Sub<Output = T> + FromPrimitive + Div<T, Output = T> + Mul<Output = T> + Zero,
 {
     pub fn var(&self) -> T {
-        let mean = self.mean();
-
-        self.flat().fold(T::zero(), |acc, val| {
-            acc + (val.clone() - mean.clone()) * (val.clone() - mean.clone())
-        }) / T::from_usize(self.shape().iter().product()).unwrap()
+        var_of(self.flat(), self.mean(), self.len())
     }
 
     pub fn var_across(&self, axis: usize) -> Vec<T> {
@@ -153,6 +113,54 @@ where
     }
 }
 
+// Reductions over the elements of an array in logical order, behind the
+// methods of both `Array` and `ArrayD`.
+
+pub(crate) fn positions_of<'a, T: PartialEq + 'a, I: Iterator<Item = &'a T>>(
+    elems: I,
+    target: &T,
+) -> Vec<usize> {
+    elems
+        .enumerate()
+        .filter(|(_, value)| *value == target)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+pub(crate) fn sum_of<'a, T, I>(elems: I) -> T
+where
+    T: Clone + Add<Output = T> + Zero + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    elems.fold(T::zero(), |acc, val| acc + val.clone())
+}
+
+pub(crate) fn prod_of<'a, T, I>(elems: I) -> T
+where
+    T: Clone + Mul<Output = T> + One + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    elems.fold(T::one(), |acc, val| acc * val.clone())
+}
+
+pub(crate) fn mean_of<'a, T, I>(elems: I, len: usize) -> T
+where
+    T: Clone + Add<Output = T> + FromPrimitive + Div<T, Output = T> + Zero + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    sum_of(elems) / T::from_usize(len).unwrap()
+}
+
+pub(crate) fn var_of<'a, T, I>(elems: I, mean: T, len: usize) -> T
+where
+    T: Clone + Sub<Output = T> + FromPrimitive + Div<T, Output = T> + Mul<Output = T> + Zero + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    elems.fold(T::zero(), |acc, val| {
+        acc + (val.clone() - mean.clone()) * (val.clone() - mean.clone())
+    }) / T::from_usize(len).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,7 +180,7 @@ mod tests {
         // 2-D array:
         // 0 1
         // 2 3
-        let array = Array::init(vec![0, 1, 2 , 3], [2, 2]);
+        let array = Array::init(vec![0, 1, 2, 3], [2, 2]);
 
         assert_eq!(array.arg_max()[0], 3);
     }
@@ -275,7 +283,7 @@ mod tests {
     fn sum() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).into_shape([2, 2]);
 
         assert_eq!(array.sum(), 10);
     }
@@ -284,7 +292,7 @@ mod tests {
     fn sum_across() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).into_shape([2, 2]);
 
         assert_eq!(array.sum_across(0), vec![3, 7]);
         assert_eq!(array.sum_across(1), vec![4, 6]);
@@ -294,7 +302,7 @@ mod tests {
     fn prod() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).into_shape([2, 2]);
 
         assert_eq!(array.prod(), 24);
     }
@@ -303,7 +311,7 @@ mod tests {
     fn prod_across() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).into_shape([2, 2]);
 
         assert_eq!(array.prod_across(0), vec![2, 12]);
         assert_eq!(array.prod_across(1), vec![3, 8]);
@@ -313,7 +321,7 @@ mod tests {
     fn mean() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).into_shape([2, 2]);
 
         assert_eq!(array.mean(), 2);
     }
@@ -322,7 +330,7 @@ mod tests {
     fn mean_across() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).into_shape([2, 2]);
 
         assert_eq!(array.mean_across(0), vec![1, 3]);
         assert_eq!(array.mean_across(1), vec![2, 3]);
@@ -0,0 +1,120 @@
+use super::broadcast::RankFits;
+use crate::{ndarray::error::unwrap_shape, Array, IntoOperand, ShapeError};
+
+// Element-wise comparisons against an array broadcast to the shape of `self`,
+// or against a scalar.
+macro_rules! impl_compare {
+    ($bound:ident, $method:ident, $try_method:ident, $op:tt) => {
+        impl<'a, T: Clone + $bound, const D: usize> Array<'a, T, D> {
+            pub fn $method<'b, Rhs: IntoOperand<'b, T, R>, const R: usize>(
+                &self,
+                rhs: Rhs,
+            ) -> Array<'a, bool, D>
+            where
+                T: 'b,
+            {
+                let () = RankFits::<D, R>::CHECK;
+
+                unwrap_shape(self.$try_method(rhs))
+            }
+
+            pub fn $try_method<'b, Rhs: IntoOperand<'b, T, R>, const R: usize>(
+                &self,
+                rhs: Rhs,
+            ) -> Result<Array<'a, bool, D>, ShapeError>
+            where
+                T: 'b,
+            {
+                self.try_zip_with(&rhs.into_operand(), |lhs, rhs| lhs $op rhs)
+            }
+        }
+    };
+}
+
+impl_compare!(PartialEq, eq_elem, try_eq_elem, ==);
+impl_compare!(PartialEq, ne_elem, try_ne_elem, !=);
+impl_compare!(PartialOrd, lt, try_lt, <);
+impl_compare!(PartialOrd, le, try_le, <=);
+impl_compare!(PartialOrd, gt, try_gt, >);
+impl_compare!(PartialOrd, ge, try_ge, >=);
+
+// Arrays are equal when they have the same shape and the same elements in
+// logical order, whatever their layouts.
+impl<'a, 'b, T: Clone + PartialEq, const D: usize> PartialEq<Array<'b, T, D>> for Array<'a, T, D> {
+    fn eq(&self, other: &Array<'b, T, D>) -> bool {
+        self.shape == other.shape && self.flat().eq(other.flat())
+    }
+}
+
+impl<'a, T: Clone + Eq, const D: usize> Eq for Array<'a, T, D> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_scalar() {
+        // 2-D array:
+        // 0.1 0.6
+        // 0.9 0.3
+        let array = Array::init(vec![0.1, 0.6, 0.9, 0.3], [2, 2]);
+
+        let mask = array.gt(0.5);
+        assert_eq!(mask.shape(), &[2, 2]);
+        assert_eq!(
+            mask.flat().copied().collect::<Vec<bool>>(),
+            vec![false, true, true, false]
+        );
+
+        assert_eq!(array.le(0.3).flat().filter(|elem| **elem).count(), 2);
+        assert_eq!(array.eq_elem(0.9).flat().filter(|elem| **elem).count(), 1);
+    }
+
+    #[test]
+    fn compare_arrays() {
+        // 2-D arrays:
+        // 1 2 3    3 2 1
+        // 4 5 6    6 5 4
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array2 = Array::init(vec![3, 2, 1, 6, 5, 4], [2, 3]);
+
+        assert_eq!(
+            array.lt(&array2).flat().copied().collect::<Vec<bool>>(),
+            vec![true, false, false, true, false, false]
+        );
+        assert_eq!(
+            array
+                .ne_elem(&array2)
+                .flat()
+                .copied()
+                .collect::<Vec<bool>>(),
+            vec![true, false, true, true, false, true]
+        );
+
+        // the row is compared against every row
+        let row = Array::init(vec![2, 2, 2], [3]);
+        assert_eq!(
+            array.ge(&row).flat().copied().collect::<Vec<bool>>(),
+            vec![false, true, true, true, true, true]
+        );
+
+        let column = Array::init(vec![1, 2, 3], [3, 1]);
+        assert_eq!(
+            array.try_gt(&column).err(),
+            Some(ShapeError::IncompatibleShapes {
+                left: vec![2, 3],
+                right: vec![3, 1]
+            })
+        );
+    }
+
+    #[test]
+    fn partial_eq() {
+        let array = Array::arange(0..6).into_shape([2, 3]);
+        let transposed = Array::init_with_order(vec![0, 3, 1, 4, 2, 5], [2, 3], crate::Order::F);
+
+        assert!(array == transposed);
+        assert!(array != array.flip(1));
+        assert!(Array::<usize, 2>::zeros([2, 3]) != Array::zeros([3, 2]));
+    }
+}
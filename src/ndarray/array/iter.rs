@@ -1,12 +1,27 @@
-use std::ops::Range;
+use std::{cmp::Reverse, iter::FusedIterator, marker::PhantomData, ops::Range, slice};
 
 use arrayvec::ArrayVec;
 
-use crate::Array;
+use super::{is_standard_layout, offset_of, IdxMap};
+use crate::{
+    ndarray::error::{check_axis, check_rank, unwrap_shape},
+    Array, ArrayViewMut, AxisSlice, IterOrder, ShapeError,
+};
 
 impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
     pub fn flat(&self) -> Iter<'_, T, D> {
-        Iter::init(self)
+        Iter::init(self, IterOrder::C)
+    }
+
+    pub fn flat_with_order(&self, order: IterOrder) -> Iter<'_, T, D> {
+        Iter::init(self, order)
+    }
+
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T, D> {
+        IndexedIter {
+            iter: self.flat(),
+            indices: Indices::init(self.shape),
+        }
     }
 
     pub fn axes(&self) -> Axes<'_, D> {
@@ -16,45 +31,526 @@ impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
     pub fn axis_view(&self, axis: usize) -> AxisView<'_, T, D> {
         AxisView::init(self, axis)
     }
+
+    pub fn try_axis_view(&self, axis: usize) -> Result<AxisView<'_, T, D>, ShapeError> {
+        AxisView::try_init(self, axis)
+    }
+
+    pub fn lanes(&self, axis: usize) -> Lanes<'_, T, D> {
+        Lanes::init(self, axis)
+    }
+
+    pub fn try_lanes(&self, axis: usize) -> Result<Lanes<'_, T, D>, ShapeError> {
+        Lanes::try_init(self, axis)
+    }
+
+    pub fn axis_iter<const R: usize>(&self, axis: usize) -> AxisIter<'_, T, D, R> {
+        AxisIter::init(self, axis)
+    }
+
+    pub fn try_axis_iter<const R: usize>(
+        &self,
+        axis: usize,
+    ) -> Result<AxisIter<'_, T, D, R>, ShapeError> {
+        AxisIter::try_init(self, axis)
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    pub fn flat_mut(&mut self) -> IterMut<'_, T, D> {
+        IterMut::init(self.view_mut())
+    }
+
+    pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T, D> {
+        IndexedIterMut {
+            indices: Indices::init(self.shape),
+            iter: self.flat_mut(),
+        }
+    }
+
+    pub fn map_inplace<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.flat_mut().for_each(f)
+    }
+
+    pub fn axis_view_mut(&mut self, axis: usize) -> AxisViewMut<'_, T, D> {
+        AxisViewMut::init(self.view_mut(), axis)
+    }
+
+    pub fn try_axis_view_mut(&mut self, axis: usize) -> Result<AxisViewMut<'_, T, D>, ShapeError> {
+        AxisViewMut::try_init(self.view_mut(), axis)
+    }
+
+    pub fn lanes_mut(&mut self, axis: usize) -> LanesMut<'_, T, D> {
+        LanesMut::init(self.view_mut(), axis)
+    }
+
+    pub fn try_lanes_mut(&mut self, axis: usize) -> Result<LanesMut<'_, T, D>, ShapeError> {
+        LanesMut::try_init(self.view_mut(), axis)
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ArrayViewMut<'a, T, D> {
+    pub fn flat_mut(&mut self) -> IterMut<'_, T, D> {
+        IterMut::init(self.view_mut())
+    }
+
+    pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T, D> {
+        IndexedIterMut {
+            indices: Indices::init(self.shape),
+            iter: self.flat_mut(),
+        }
+    }
+
+    pub fn map_inplace<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.flat_mut().for_each(f)
+    }
+
+    pub fn axis_view_mut(&mut self, axis: usize) -> AxisViewMut<'_, T, D> {
+        AxisViewMut::init(self.view_mut(), axis)
+    }
+
+    pub fn try_axis_view_mut(&mut self, axis: usize) -> Result<AxisViewMut<'_, T, D>, ShapeError> {
+        AxisViewMut::try_init(self.view_mut(), axis)
+    }
+
+    pub fn lanes_mut(&mut self, axis: usize) -> LanesMut<'_, T, D> {
+        LanesMut::init(self.view_mut(), axis)
+    }
+
+    pub fn try_lanes_mut(&mut self, axis: usize) -> Result<LanesMut<'_, T, D>, ShapeError> {
+        LanesMut::try_init(self.view_mut(), axis)
+    }
+}
+
+impl<'a, T: Clone> Array<'a, T, 2> {
+    pub fn rows(&self) -> AxisIter<'_, T, 2, 1> {
+        self.axis_iter(0)
+    }
+
+    pub fn columns(&self) -> AxisIter<'_, T, 2, 1> {
+        self.axis_iter(1)
+    }
 }
 
 pub struct Iter<'a, T: Clone, const D: usize> {
-    array: &'a Array<'a, T, D>,
-    indices: [usize; D],
+    inner: IterInner<'a, T, [usize; D], [IdxMap; D]>,
 }
 
 impl<'a, T: Clone, const D: usize> Iter<'a, T, D> {
-    fn init(array: &'a Array<'a, T, D>) -> Self {
+    fn init(array: &'a Array<'_, T, D>, order: IterOrder) -> Self {
+        let mut axes = [0; D];
+        axes.iter_mut()
+            .enumerate()
+            .for_each(|(idx, axis)| *axis = idx);
+
+        let mut idx_maps = array.idx_maps;
+
+        match order {
+            IterOrder::C => {}
+            IterOrder::F => axes.reverse(),
+            IterOrder::Memory => {
+                axes.sort_by_key(|axis| {
+                    Reverse(idx_maps[*axis].m.unsigned_abs() * array.strides[*axis])
+                });
+
+                // walk the axes that run backwards in memory from their end
+                for (axis, idx_map) in idx_maps.iter_mut().enumerate() {
+                    if idx_map.m < 0 && array.shape[axis] > 0 {
+                        idx_map.append_b(array.shape[axis] as isize - 1);
+                        idx_map.m *= -1;
+                    }
+                }
+            }
+        }
+
+        let shape = axes.map(|axis| array.shape[axis]);
+        let strides = axes.map(|axis| array.strides[axis]);
+        let idx_maps = axes.map(|axis| idx_maps[axis]);
+
         Iter {
-            array,
-            indices: [0; D],
+            inner: IterInner::init(
+                &array.vec,
+                Walk::init(array.offset, shape, strides, idx_maps),
+            ),
         }
     }
+}
+
+impl<'a, T: Clone, const D: usize> Iterator for Iter<'a, T, D> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
 
-    fn increment_indices(&mut self) {
-        self.increment_idx_at_axis(D - 1)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
     }
+}
+
+impl<'a, T: Clone, const D: usize> DoubleEndedIterator for Iter<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
 
-    fn increment_idx_at_axis(&mut self, axis: usize) {
-        self.indices[axis] += 1;
+impl<'a, T: Clone, const D: usize> ExactSizeIterator for Iter<'a, T, D> {}
 
-        if axis != 0 && self.indices[axis] >= self.array.shape[axis] {
-            self.indices[axis] = 0;
+impl<'a, T: Clone, const D: usize> FusedIterator for Iter<'a, T, D> {}
 
-            self.increment_idx_at_axis(axis - 1);
+// The elements of a walk over a buffer, behind the element iterators of both
+// `Array` and `ArrayD`.
+pub(crate) enum IterInner<'a, T, S, M> {
+    Contiguous(slice::Iter<'a, T>),
+    Strided(&'a [T], Strided<S, M>),
+}
+
+impl<'a, T, S, M> IterInner<'a, T, S, M> {
+    pub(crate) fn init(vec: &'a [T], walk: Walk<S, M>) -> Self {
+        match walk {
+            Walk::Contiguous(range) => IterInner::Contiguous(vec[range].iter()),
+            Walk::Strided(strided) => IterInner::Strided(vec, strided),
         }
     }
 }
 
-impl<'a, T: Clone, const D: usize> Iterator for Iter<'a, T, D> {
+impl<'a, T, S, M> Iterator for IterInner<'a, T, S, M>
+where
+    S: AsRef<[usize]> + AsMut<[usize]> + Clone,
+    M: AsRef<[IdxMap]>,
+{
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let item = self.array.get(self.indices);
+        match self {
+            IterInner::Contiguous(iter) => iter.next(),
+            IterInner::Strided(vec, strided) => strided.next_index().map(|index| &vec[index]),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self {
+            IterInner::Contiguous(iter) => iter.len(),
+            IterInner::Strided(_, strided) => strided.remaining,
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, S, M> DoubleEndedIterator for IterInner<'a, T, S, M>
+where
+    S: AsRef<[usize]> + AsMut<[usize]> + Clone,
+    M: AsRef<[IdxMap]>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            IterInner::Contiguous(iter) => iter.next_back(),
+            IterInner::Strided(vec, strided) => strided.next_back_index().map(|index| &vec[index]),
+        }
+    }
+}
+
+pub struct IterMut<'a, T: Clone, const D: usize> {
+    inner: IterMutInner<'a, T, D>,
+}
+
+enum IterMutInner<'a, T, const D: usize> {
+    Contiguous(slice::IterMut<'a, T>),
+    // the strided walk never yields the same buffer index twice, so handing out
+    // a mutable reference per index through the raw pointer does not alias.
+    Strided(
+        *mut T,
+        Strided<[usize; D], [IdxMap; D]>,
+        PhantomData<&'a mut T>,
+    ),
+}
+
+// SAFETY: `IterMut` hands out `&mut T`s like `slice::IterMut` does.
+unsafe impl<'a, T: Clone + Send, const D: usize> Send for IterMut<'a, T, D> {}
+unsafe impl<'a, T: Clone + Sync, const D: usize> Sync for IterMut<'a, T, D> {}
+
+impl<'a, T: Clone, const D: usize> IterMut<'a, T, D> {
+    pub(crate) fn init(view: ArrayViewMut<'a, T, D>) -> Self {
+        let inner = match Walk::init(view.offset, view.shape, view.strides, view.idx_maps) {
+            Walk::Contiguous(range) => IterMutInner::Contiguous(view.vec[range].iter_mut()),
+            Walk::Strided(strided) => {
+                debug_assert!(strided.remaining == 0 || strided.max_index() < view.vec.len());
+
+                IterMutInner::Strided(view.vec.as_mut_ptr(), strided, PhantomData)
+            }
+        };
+
+        IterMut { inner }
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Iterator for IterMut<'a, T, D> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IterMutInner::Contiguous(iter) => iter.next(),
+            IterMutInner::Strided(ptr, strided, _) => strided
+                .next_index()
+                .map(|index| unsafe { &mut *ptr.add(index) }),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match &self.inner {
+            IterMutInner::Contiguous(iter) => iter.len(),
+            IterMutInner::Strided(_, strided, _) => strided.remaining,
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Clone, const D: usize> DoubleEndedIterator for IterMut<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IterMutInner::Contiguous(iter) => iter.next_back(),
+            IterMutInner::Strided(ptr, strided, _) => strided
+                .next_back_index()
+                .map(|index| unsafe { &mut *ptr.add(index) }),
+        }
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ExactSizeIterator for IterMut<'a, T, D> {}
+
+impl<'a, T: Clone, const D: usize> FusedIterator for IterMut<'a, T, D> {}
+
+pub struct IndexedIter<'a, T: Clone, const D: usize> {
+    iter: Iter<'a, T, D>,
+    indices: Indices<D>,
+}
+
+impl<'a, T: Clone, const D: usize> Iterator for IndexedIter<'a, T, D> {
+    type Item = ([usize; D], &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elem = self.iter.next()?;
+
+        Some((self.indices.advance(), elem))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ExactSizeIterator for IndexedIter<'a, T, D> {}
+
+pub struct IndexedIterMut<'a, T: Clone, const D: usize> {
+    iter: IterMut<'a, T, D>,
+    indices: Indices<D>,
+}
+
+impl<'a, T: Clone, const D: usize> Iterator for IndexedIterMut<'a, T, D> {
+    type Item = ([usize; D], &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elem = self.iter.next()?;
 
-        self.increment_indices();
+        Some((self.indices.advance(), elem))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ExactSizeIterator for IndexedIterMut<'a, T, D> {}
+
+// Logical indices of the elements in C order, alongside a flat iterator.
+pub(super) struct Indices<const D: usize> {
+    shape: [usize; D],
+    next: [usize; D],
+}
+
+impl<const D: usize> Indices<D> {
+    pub(super) fn init(shape: [usize; D]) -> Self {
+        Indices {
+            shape,
+            next: [0; D],
+        }
+    }
+
+    pub(super) fn advance(&mut self) -> [usize; D] {
+        let current = self.next;
+
+        for axis in (0..D).rev() {
+            self.next[axis] += 1;
 
-        item
+            if self.next[axis] < self.shape[axis] {
+                break;
+            }
+
+            self.next[axis] = 0;
+        }
+
+        current
+    }
+}
+
+// How to walk the elements of a layout in the order of its axes: a range of
+// the buffer when the layout is standard, otherwise a strided walk. The
+// per-axis values live in fixed-size arrays for `Array` and in vectors for
+// `ArrayD`.
+pub(crate) enum Walk<S, M> {
+    Contiguous(Range<usize>),
+    Strided(Strided<S, M>),
+}
+
+impl<S, M> Walk<S, M>
+where
+    S: AsRef<[usize]> + AsMut<[usize]> + Clone,
+    M: AsRef<[IdxMap]>,
+{
+    pub(crate) fn init(offset: usize, shape: S, strides: S, idx_maps: M) -> Self {
+        let len = shape.as_ref().iter().product();
+
+        let mut front = shape.clone();
+        front.as_mut().fill(0);
+
+        let first = offset_of(
+            offset,
+            shape.as_ref(),
+            strides.as_ref(),
+            idx_maps.as_ref(),
+            front.as_ref(),
+        );
+
+        match first {
+            Some(first)
+                if is_standard_layout(shape.as_ref(), strides.as_ref(), idx_maps.as_ref()) =>
+            {
+                Walk::Contiguous(first..first + len)
+            }
+            _ => Walk::Strided(Strided::init(
+                shape,
+                strides,
+                idx_maps,
+                front,
+                first.unwrap_or(0),
+                len,
+            )),
+        }
+    }
+}
+
+// Walks a strided layout from both ends, updating the buffer index of each end
+// incrementally instead of recomputing it from the indices.
+pub(crate) struct Strided<S, M> {
+    shape: S,
+    strides: S,
+    idx_maps: M,
+    front: S,
+    front_index: isize,
+    back: S,
+    back_index: isize,
+    pub(crate) remaining: usize,
+}
+
+impl<S, M> Strided<S, M>
+where
+    S: AsRef<[usize]> + AsMut<[usize]> + Clone,
+    M: AsRef<[IdxMap]>,
+{
+    fn init(shape: S, strides: S, idx_maps: M, front: S, first: usize, len: usize) -> Self {
+        let mut back = shape.clone();
+        back.as_mut()
+            .iter_mut()
+            .for_each(|len| *len = len.saturating_sub(1));
+
+        let mut strided = Strided {
+            shape,
+            strides,
+            idx_maps,
+            front,
+            front_index: first as isize,
+            back,
+            back_index: first as isize,
+            remaining: len,
+        };
+
+        strided.back_index = (0..strided.ndim()).fold(first as isize, |acc, axis| {
+            acc + strided.step(axis) * strided.back.as_ref()[axis] as isize
+        });
+
+        strided
+    }
+
+    fn ndim(&self) -> usize {
+        self.shape.as_ref().len()
+    }
+
+    // distance in the buffer between neighbouring elements along `axis`
+    fn step(&self, axis: usize) -> isize {
+        self.idx_maps.as_ref()[axis].m * self.strides.as_ref()[axis] as isize
+    }
+
+    // largest buffer index of a walk that has not started yet
+    fn max_index(&self) -> usize {
+        (0..self.ndim()).fold(self.front_index, |acc, axis| {
+            acc + self.step(axis).max(0) * (self.shape.as_ref()[axis] as isize - 1)
+        }) as usize
+    }
+
+    pub(crate) fn next_index(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = self.front_index as usize;
+        self.remaining -= 1;
+
+        for axis in (0..self.ndim()).rev() {
+            let step = self.step(axis);
+            let len = self.shape.as_ref()[axis];
+            let front = &mut self.front.as_mut()[axis];
+
+            *front += 1;
+            self.front_index += step;
+
+            if *front < len {
+                break;
+            }
+
+            self.front_index -= step * len as isize;
+            *front = 0;
+        }
+
+        Some(index)
+    }
+
+    pub(crate) fn next_back_index(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = self.back_index as usize;
+        self.remaining -= 1;
+
+        for axis in (0..self.ndim()).rev() {
+            let step = self.step(axis);
+            let len = self.shape.as_ref()[axis];
+            let back = &mut self.back.as_mut()[axis];
+
+            if *back > 0 {
+                *back -= 1;
+                self.back_index -= step;
+
+                break;
+            }
+
+            *back = len - 1;
+            self.back_index += step * *back as isize;
+        }
+
+        Some(index)
     }
 }
 
@@ -99,21 +595,26 @@ pub struct AxisView<'a, T: Clone, const D: usize> {
 
 impl<'a, T: Clone, const D: usize> AxisView<'a, T, D> {
     pub fn init(array: &'a Array<'a, T, D>, axis: usize) -> AxisView<'a, T, D> {
-        if axis >= D {
-            panic!("Axis out of bound: {} > {}", axis, D);
-        }
+        unwrap_shape(AxisView::try_init(array, axis))
+    }
+
+    pub fn try_init(
+        array: &'a Array<'a, T, D>,
+        axis: usize,
+    ) -> Result<AxisView<'a, T, D>, ShapeError> {
+        check_axis(axis, D)?;
 
         let mut slice = ArrayVec::new_const();
         for (shape, _) in array.axes() {
             slice.push(0..shape);
         }
 
-        AxisView {
+        Ok(AxisView {
             array,
             slice: slice.into_inner().unwrap(),
             axis,
             idx: 0,
-        }
+        })
     }
 }
 
@@ -134,9 +635,242 @@ impl<'a, T: Clone, const D: usize> Iterator for AxisView<'a, T, D> {
     }
 }
 
+// Every `ArrayViewMut` borrows the whole buffer as `&mut [T]`, not just its own
+// elements, so two sibling views alive at once would alias even though they
+// never touch the same element. `Iterator` would allow that through `collect`,
+// so the views are lent out one at a time instead, each borrowing the iterator.
+pub struct AxisViewMut<'a, T: Clone, const D: usize> {
+    view: ArrayViewMut<'a, T, D>,
+    axis: usize,
+    idx: usize,
+}
+
+impl<'a, T: Clone, const D: usize> AxisViewMut<'a, T, D> {
+    pub fn init(view: ArrayViewMut<'a, T, D>, axis: usize) -> AxisViewMut<'a, T, D> {
+        unwrap_shape(AxisViewMut::try_init(view, axis))
+    }
+
+    pub fn try_init(
+        view: ArrayViewMut<'a, T, D>,
+        axis: usize,
+    ) -> Result<AxisViewMut<'a, T, D>, ShapeError> {
+        check_axis(axis, D)?;
+
+        Ok(AxisViewMut { view, axis, idx: 0 })
+    }
+
+    pub fn next_view(&mut self) -> Option<ArrayViewMut<'_, T, D>> {
+        if self.idx < self.view.shape[self.axis] {
+            let mut slice = self.view.shape.map(|len| AxisSlice::from(0..len));
+            slice[self.axis] = AxisSlice::from(self.idx);
+
+            self.idx += 1;
+
+            Some(self.view.slice_mut(&slice))
+        } else {
+            None
+        }
+    }
+
+    pub fn for_each<F: FnMut(ArrayViewMut<'_, T, D>)>(mut self, mut f: F) {
+        while let Some(view) = self.next_view() {
+            f(view);
+        }
+    }
+}
+
+pub struct Lanes<'a, T: Clone, const D: usize> {
+    array: &'a Array<'a, T, D>,
+    starts: LaneStarts<D>,
+}
+
+impl<'a, T: Clone, const D: usize> Lanes<'a, T, D> {
+    pub fn init(array: &'a Array<'a, T, D>, axis: usize) -> Lanes<'a, T, D> {
+        unwrap_shape(Lanes::try_init(array, axis))
+    }
+
+    pub fn try_init(
+        array: &'a Array<'a, T, D>,
+        axis: usize,
+    ) -> Result<Lanes<'a, T, D>, ShapeError> {
+        check_axis(axis, D)?;
+
+        Ok(Lanes {
+            array,
+            starts: LaneStarts::init(array.shape, axis),
+        })
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Iterator for Lanes<'a, T, D> {
+    type Item = Array<'a, T, 1>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let array = self.array;
+        let axis = self.starts.axis;
+        let offset = self
+            .starts
+            .next(array.offset, &array.strides, &array.idx_maps)?;
+
+        Some(Array {
+            vec: array.vec.view(),
+            shape: [array.shape[axis]],
+            strides: [array.strides[axis]],
+            idx_maps: [array.idx_maps[axis]],
+            offset,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.starts.remaining, Some(self.starts.remaining))
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ExactSizeIterator for Lanes<'a, T, D> {}
+
+// Mutable lanes are lent out one at a time, like `AxisViewMut`: each lane
+// borrows the whole buffer, so lanes alive at once would alias.
+pub struct LanesMut<'a, T: Clone, const D: usize> {
+    view: ArrayViewMut<'a, T, D>,
+    starts: LaneStarts<D>,
+}
+
+impl<'a, T: Clone, const D: usize> LanesMut<'a, T, D> {
+    pub fn init(view: ArrayViewMut<'a, T, D>, axis: usize) -> LanesMut<'a, T, D> {
+        unwrap_shape(LanesMut::try_init(view, axis))
+    }
+
+    pub fn try_init(
+        view: ArrayViewMut<'a, T, D>,
+        axis: usize,
+    ) -> Result<LanesMut<'a, T, D>, ShapeError> {
+        check_axis(axis, D)?;
+
+        Ok(LanesMut {
+            starts: LaneStarts::init(view.shape, axis),
+            view,
+        })
+    }
+
+    pub fn next_view(&mut self) -> Option<ArrayViewMut<'_, T, 1>> {
+        let view = &mut self.view;
+        let axis = self.starts.axis;
+        let offset = self
+            .starts
+            .next(view.offset, &view.strides, &view.idx_maps)?;
+
+        Some(ArrayViewMut {
+            vec: &mut *view.vec,
+            shape: [view.shape[axis]],
+            strides: [view.strides[axis]],
+            idx_maps: [view.idx_maps[axis]],
+            offset,
+        })
+    }
+
+    pub fn for_each<F: FnMut(ArrayViewMut<'_, T, 1>)>(mut self, mut f: F) {
+        while let Some(view) = self.next_view() {
+            f(view);
+        }
+    }
+}
+
+// Buffer offsets of the lanes along an axis: the offset of a lane accounts for
+// every other axis, while the lane itself keeps the index map of `axis`.
+struct LaneStarts<const D: usize> {
+    axis: usize,
+    indices: Indices<D>,
+    remaining: usize,
+}
+
+impl<const D: usize> LaneStarts<D> {
+    fn init(mut shape: [usize; D], axis: usize) -> Self {
+        shape[axis] = 1;
+
+        LaneStarts {
+            axis,
+            indices: Indices::init(shape),
+            remaining: shape.iter().product(),
+        }
+    }
+
+    fn next(
+        &mut self,
+        offset: usize,
+        strides: &[usize; D],
+        idx_maps: &[IdxMap; D],
+    ) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let indices = self.indices.advance();
+
+        Some(
+            (0..D)
+                .filter(|axis| *axis != self.axis)
+                .fold(offset, |acc, axis| {
+                    acc + idx_maps[axis].map(indices[axis]) * strides[axis]
+                }),
+        )
+    }
+}
+
+pub struct AxisIter<'a, T: Clone, const D: usize, const R: usize> {
+    array: &'a Array<'a, T, D>,
+    axis: usize,
+    idx: usize,
+}
+
+impl<'a, T: Clone, const D: usize, const R: usize> AxisIter<'a, T, D, R> {
+    pub fn init(array: &'a Array<'a, T, D>, axis: usize) -> AxisIter<'a, T, D, R> {
+        unwrap_shape(AxisIter::try_init(array, axis))
+    }
+
+    pub fn try_init(
+        array: &'a Array<'a, T, D>,
+        axis: usize,
+    ) -> Result<AxisIter<'a, T, D, R>, ShapeError> {
+        check_rank(R + 1, D)?;
+        check_axis(axis, D)?;
+
+        Ok(AxisIter {
+            array,
+            axis,
+            idx: 0,
+        })
+    }
+}
+
+impl<'a, T: Clone, const D: usize, const R: usize> Iterator for AxisIter<'a, T, D, R> {
+    type Item = Array<'a, T, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx < self.array.shape[self.axis] {
+            let view = self.array.index_axis(self.axis, self.idx);
+
+            self.idx += 1;
+
+            Some(view)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.array.shape[self.axis] - self.idx;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Clone, const D: usize, const R: usize> ExactSizeIterator for AxisIter<'a, T, D, R> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s;
 
     #[test]
     fn iter() {
@@ -150,4 +884,341 @@ mod tests {
             vec![1, 2, 3, 4, 5, 6]
         )
     }
+
+    #[test]
+    fn try_axis_view() {
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        assert_eq!(array.try_axis_view(1).map(|views| views.count()), Ok(3));
+        assert_eq!(
+            array.try_axis_view(2).map(|views| views.count()),
+            Err(ShapeError::AxisOutOfBounds { axis: 2, ndim: 2 })
+        );
+    }
+
+    #[test]
+    fn axis_iter() {
+        // 3-D array:
+        // 0 1 2    6 7  8
+        // 3 4 5    9 10 11
+        let array = Array::arange(0..12).into_shape([2, 2, 3]);
+
+        let sums: Vec<usize> = array.axis_iter::<2>(2).map(|matrix| matrix.sum()).collect();
+
+        assert_eq!(sums, vec![18, 22, 26]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let rows: Vec<Vec<usize>> = array
+            .rows()
+            .map(|row| row.flat().copied().collect())
+            .collect();
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let columns = array.columns();
+        assert_eq!(columns.len(), 3);
+        assert_eq!(
+            columns.map(|column| column.max()).collect::<Vec<_>>(),
+            vec![Some(4), Some(5), Some(6)]
+        );
+    }
+
+    #[test]
+    fn iter_with_order() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(
+            array
+                .flat_with_order(IterOrder::F)
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+
+        // flipped transpose of the array:
+        // 6 3
+        // 5 2
+        // 4 1
+        let view = array.t().flip(0).flip(1).into_shared();
+
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![6, 3, 5, 2, 4, 1]
+        );
+        assert_eq!(
+            view.flat_with_order(IterOrder::Memory)
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn iter_contiguous() {
+        let array = Array::arange(0..12).into_shape([3, 4]);
+
+        // rows are contiguous in memory
+        let rows = array.slice(&[1..3, 0..4]);
+        let mut iter = rows.flat();
+
+        assert_eq!(iter.len(), 8);
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next_back(), Some(&11));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(
+            iter.copied().collect::<Vec<usize>>(),
+            vec![5, 6, 7, 8, 9, 10]
+        );
+    }
+
+    #[test]
+    fn iter_strided() {
+        // 2-D array:
+        // 0 1 2 3
+        // 4 5 6 7
+        // 8 9 10 11
+        let array = Array::arange(0..12).into_shape([3, 4]);
+
+        // 10 8
+        // 6 4
+        let view = array.slice(&s![1..;-1, ..3;-2]);
+        let mut iter = view.flat();
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&10));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next_back(), Some(&6));
+        assert_eq!(iter.next(), Some(&8));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(
+            array.t().flat().rev().copied().collect::<Vec<usize>>(),
+            vec![11, 7, 3, 10, 6, 2, 9, 5, 1, 8, 4, 0]
+        );
+    }
+
+    #[test]
+    fn iter_empty_and_scalar() {
+        let empty: Array<usize, 2> = Array::zeros([0, 3]);
+        assert_eq!(empty.flat().len(), 0);
+        assert_eq!(empty.t().flat().next(), None);
+
+        let scalar = Array::init(vec![7], []);
+        assert_eq!(scalar.flat().copied().collect::<Vec<usize>>(), vec![7]);
+    }
+
+    #[test]
+    fn flat_mut() {
+        // transposed 2-D array:
+        // 0 3
+        // 1 4
+        // 2 5
+        let mut array = Array::arange(0..6).into_shape([2, 3]).transpose();
+
+        // the flipped view is walked in its own logical order:
+        // 5 2
+        // 4 1
+        // 3 0
+        let mut view = array.slice_mut(&s![..;-1, ..;-1]);
+        let mut counter = 0;
+        view.flat_mut().for_each(|elem| {
+            *elem = counter;
+            counter += 1;
+        });
+
+        assert_eq!(
+            view.view().flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![5, 4, 3, 2, 1, 0]
+        );
+
+        // 2-D array:
+        // 0 1 2
+        // 3 4 5
+        let mut array = Array::arange(0..6).into_shape([2, 3]);
+
+        let mut strided = array.slice_mut(&s![.., ..;2]);
+        let mut iter = strided.flat_mut();
+        assert_eq!(iter.len(), 4);
+        *iter.next_back().unwrap() = 10;
+        *iter.next().unwrap() = 20;
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![20, 1, 2, 3, 4, 10]
+        );
+    }
+
+    #[test]
+    fn indexed_iter() {
+        // 2-D array:
+        // 0 1 2
+        // 3 4 5
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        // 5 3
+        // 2 0
+        let view = array.slice(&s![..;-1, ..;-2]);
+
+        assert_eq!(
+            view.indexed_iter()
+                .map(|(indices, elem)| (indices, *elem))
+                .collect::<Vec<_>>(),
+            vec![([0, 0], 5), ([0, 1], 3), ([1, 0], 2), ([1, 1], 0)]
+        );
+        assert_eq!(
+            view.indexed_iter()
+                .find(|(_, elem)| **elem == 2)
+                .map(|(indices, _)| indices),
+            Some([1, 0])
+        );
+    }
+
+    #[test]
+    fn indexed_iter_mut() {
+        let mut array = Array::zeros([2, 3]);
+
+        array
+            .slice_mut(&[0..2, 1..3])
+            .indexed_iter_mut()
+            .for_each(|([row, column], elem)| *elem = row * 10 + column);
+
+        // 0 0 1
+        // 0 10 11
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 0, 1, 0, 10, 11]
+        );
+    }
+
+    #[test]
+    fn lanes() {
+        // 3-D array:
+        // 0 1 2    6 7  8
+        // 3 4 5    9 10 11
+        let array = Array::arange(0..12).into_shape([2, 2, 3]);
+
+        let fibres: Vec<Vec<usize>> = array
+            .lanes(0)
+            .map(|lane| lane.flat().copied().collect())
+            .collect();
+        assert_eq!(
+            fibres,
+            vec![
+                vec![0, 6],
+                vec![1, 7],
+                vec![2, 8],
+                vec![3, 9],
+                vec![4, 10],
+                vec![5, 11]
+            ]
+        );
+
+        assert_eq!(array.lanes(2).len(), 4);
+        assert_eq!(
+            array
+                .lanes(1)
+                .map(|lane| lane.sum())
+                .collect::<Vec<usize>>(),
+            vec![3, 5, 7, 15, 17, 19]
+        );
+
+        // lanes of a flipped view follow the flip:
+        // 2 1 0
+        // 5 4 3
+        let matrix = Array::arange(0..6).into_shape([2, 3]);
+        let flipped = matrix.flip(1);
+        let rows: Vec<Vec<usize>> = flipped
+            .lanes(1)
+            .map(|lane| lane.flat().copied().collect())
+            .collect();
+        assert_eq!(rows, vec![vec![2, 1, 0], vec![5, 4, 3]]);
+
+        assert_eq!(
+            array.try_lanes(3).map(|lanes| lanes.len()),
+            Err(ShapeError::AxisOutOfBounds { axis: 3, ndim: 3 })
+        );
+    }
+
+    #[test]
+    fn lanes_mut() {
+        // 2-D array:
+        // 0 1 2
+        // 3 4 5
+        let mut array = Array::arange(0..6).into_shape([2, 3]);
+
+        // cumulative sum down each column
+        array.lanes_mut(0).for_each(|mut column| {
+            let mut total = 0;
+            column.map_inplace(|elem| {
+                total += *elem;
+                *elem = total;
+            });
+        });
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 5, 7]
+        );
+    }
+
+    #[test]
+    fn map_inplace() {
+        let mut array = Array::arange(0..4).into_shape([2, 2]);
+
+        array.map_inplace(|elem| *elem *= 2);
+        array
+            .slice_mut(&[0..2, 1..2])
+            .map_inplace(|elem| *elem += 1);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 3, 4, 7]
+        );
+    }
+
+    #[test]
+    fn axis_view_mut() {
+        // 2-D array:
+        // 1 3
+        // 2 6
+        let mut array = Array::init(vec![1.0, 3.0, 2.0, 6.0], [2, 2]);
+
+        // normalize each row by its sum
+        array.axis_view_mut(0).for_each(|mut row| {
+            let sum = row.view().sum();
+            row.map_inplace(|elem| *elem /= sum);
+        });
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<f64>>(),
+            vec![0.25, 0.75, 0.25, 0.75]
+        );
+
+        let mut columns = array.axis_view_mut(1);
+        let mut first = columns.next_view().unwrap();
+        first.fill(0.0);
+        assert_eq!(first.shape(), &[2, 1]);
+
+        assert!(columns.next_view().is_some());
+        assert!(columns.next_view().is_none());
+        assert_eq!(
+            array.try_axis_view_mut(2).map(|_| ()),
+            Err(ShapeError::AxisOutOfBounds { axis: 2, ndim: 2 })
+        );
+    }
 }
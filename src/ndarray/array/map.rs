@@ -0,0 +1,189 @@
+use super::{broadcast::fixed_rank, broadcast_shape, covers_buffer};
+use crate::{ndarray::error::unwrap_shape, ndarray::storage::Storage, Array, ShapeError, Zip};
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    /// Applies `f` to every element into a new array of the same shape.
+    ///
+    /// An array that covers its whole buffer is mapped buffer and all, keeping
+    /// its layout. Otherwise only the elements of the view are mapped, in
+    /// standard layout, so `f` never sees elements outside of it.
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let array = Array::arange(0..4).into_shape([2, 2]);
+    ///
+    /// let labels = array.map(|val| format!("#{}", val));
+    /// assert_eq!(labels[[1, 0]], "#2");
+    /// ```
+    pub fn map<'b, U: Clone, F: FnMut(&T) -> U>(&self, f: F) -> Array<'b, U, D> {
+        if covers_buffer(&self.shape, &self.strides, self.vec.len()) {
+            Array {
+                vec: Storage::from(self.vec.iter().map(f).collect::<Vec<U>>()),
+                shape: self.shape,
+                strides: self.strides,
+                idx_maps: self.idx_maps,
+                offset: self.offset,
+            }
+        } else {
+            Array::init(self.flat().map(f).collect(), self.shape)
+        }
+    }
+
+    /// Like [`Array::map`], but `f` takes the elements by value.
+    pub fn mapv<'b, U: Clone, F: FnMut(T) -> U>(&self, mut f: F) -> Array<'b, U, D>
+    where
+        T: Copy,
+    {
+        self.map(|val| f(*val))
+    }
+
+    /// Like [`Array::map`], but consumes the array and moves the elements out
+    /// of an owned buffer instead of cloning them.
+    pub fn map_into<'b, U: Clone, F: FnMut(T) -> U>(self, f: F) -> Array<'b, U, D> {
+        if covers_buffer(&self.shape, &self.strides, self.vec.len()) {
+            let vec: Vec<U> = match self.vec {
+                Storage::Owned(vec) => vec.into_iter().map(f).collect(),
+                vec => vec.iter().cloned().map(f).collect(),
+            };
+
+            Array {
+                vec: Storage::from(vec),
+                shape: self.shape,
+                strides: self.strides,
+                idx_maps: self.idx_maps,
+                offset: self.offset,
+            }
+        } else {
+            Array::init(self.flat().cloned().map(f).collect(), self.shape)
+        }
+    }
+
+    /// Combines the elements of both arrays after broadcasting them to a common
+    /// shape, which must have the rank of `self`.
+    ///
+    /// Panics when the shapes can not be broadcast, see
+    /// [`Array::try_zip_with`].
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let array = Array::init(vec![3.0, 6.0, 0.0, 15.0], [2, 2]);
+    /// let other = Array::init(vec![4.0, 8.0], [2]);
+    ///
+    /// let hypot = array.zip_with(&other, |a: &f64, b: &f64| a.hypot(*b));
+    /// assert_eq!(hypot.flat().copied().collect::<Vec<f64>>(), vec![5.0, 10.0, 4.0, 17.0]);
+    /// ```
+    pub fn zip_with<'b, U: Clone, V: Clone, F, const R: usize>(
+        &self,
+        rhs: &Array<'_, U, R>,
+        f: F,
+    ) -> Array<'b, V, D>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        unwrap_shape(self.try_zip_with(rhs, f))
+    }
+
+    /// Fallible version of [`Array::zip_with`], failing with
+    /// [`ShapeError::IncompatibleShapes`] when the shapes can not be broadcast
+    /// and with [`ShapeError::BroadcastRankMismatch`] when the common shape has
+    /// more axes than `self`.
+    pub fn try_zip_with<'b, U: Clone, V: Clone, F, const R: usize>(
+        &self,
+        rhs: &Array<'_, U, R>,
+        f: F,
+    ) -> Result<Array<'b, V, D>, ShapeError>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        let shape = fixed_rank(broadcast_shape(&self.shape, &rhs.shape)?)?;
+
+        let lhs = self.try_broadcast(shape)?;
+        let rhs = rhs.try_broadcast(shape)?;
+
+        Ok(Zip::init(&lhs).and(&rhs).map_collect(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn map() {
+        // 2-D array:
+        // 0 1 2
+        // 3 4 5
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        let labels = array.map(|val| format!("#{}", val));
+        assert_eq!(labels.shape(), &[2, 3]);
+        assert_eq!(labels[[1, 2]], "#5");
+
+        // a transposed array keeps its layout
+        let squares = array.t().mapv(|val| val * val);
+        assert_eq!(squares.strides(), array.t().strides());
+        assert_eq!(
+            squares.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 9, 1, 16, 4, 25]
+        );
+
+        // a view only maps its own elements:
+        // 5 3
+        let view = array.slice(&s![1..2, ..;-2]);
+        let halves = view.mapv(|val| val as f64 / 2.0);
+        assert!(halves.is_standard_layout());
+        assert_eq!(halves.flat().copied().collect::<Vec<f64>>(), vec![2.5, 1.5]);
+    }
+
+    #[test]
+    fn map_into() {
+        let words = Array::init(
+            vec![String::from("a"), String::from("bb"), String::from("ccc")],
+            [3],
+        );
+
+        let lengths = words.map_into(|word| word.len());
+
+        assert_eq!(
+            lengths.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn zip_with() {
+        // 2-D arrays:
+        // 3 4    4 3
+        // 6 8    8 6
+        let array = Array::init(vec![3.0, 4.0, 6.0, 8.0], [2, 2]);
+        let swapped = array.flip(1);
+
+        let hypot = array.zip_with(&swapped, |a: &f64, b: &f64| a.hypot(*b));
+        assert_eq!(
+            hypot.flat().copied().collect::<Vec<f64>>(),
+            vec![5.0, 5.0, 10.0, 10.0]
+        );
+
+        let scales = Array::init(vec![1.0, 0.5], [2, 1]);
+        assert_eq!(
+            array
+                .zip_with(&scales, |a, b| a * b)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![3.0, 4.0, 3.0, 4.0]
+        );
+        assert_eq!(
+            array
+                .try_zip_with(&Array::init(vec![1.0; 3], [3]), |a, b| a + b)
+                .err(),
+            Some(ShapeError::IncompatibleShapes {
+                left: vec![2, 2],
+                right: vec![3]
+            })
+        );
+    }
+}
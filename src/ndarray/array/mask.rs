@@ -0,0 +1,179 @@
+use crate::{
+    ndarray::error::{check_same_shape, unwrap_shape},
+    Array, ArrayViewMut, ShapeError, Zip,
+};
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    pub fn select_mask<'b>(&self, mask: &Array<'_, bool, D>) -> Array<'b, T, 1> {
+        unwrap_shape(self.try_select_mask(mask))
+    }
+
+    // The elements where the mask is true, in logical order.
+    pub fn try_select_mask<'b>(
+        &self,
+        mask: &Array<'_, bool, D>,
+    ) -> Result<Array<'b, T, 1>, ShapeError> {
+        check_same_shape(&self.shape, &mask.shape)?;
+
+        let mut vec = vec![];
+        Zip::init(self).and(mask).for_each(|elem, selected| {
+            if *selected {
+                vec.push(elem.clone());
+            }
+        });
+
+        let len = vec.len();
+        Array::try_init(vec, [len])
+    }
+
+    pub fn assign_mask(&mut self, mask: &Array<'_, bool, D>, val: T) {
+        self.view_mut().assign_mask(mask, val)
+    }
+
+    pub fn try_assign_mask(&mut self, mask: &Array<'_, bool, D>, val: T) -> Result<(), ShapeError> {
+        self.view_mut().try_assign_mask(mask, val)
+    }
+
+    pub fn assign_mask_from(&mut self, mask: &Array<'_, bool, D>, values: &Array<'_, T, 1>) {
+        self.view_mut().assign_mask_from(mask, values)
+    }
+
+    pub fn try_assign_mask_from(
+        &mut self,
+        mask: &Array<'_, bool, D>,
+        values: &Array<'_, T, 1>,
+    ) -> Result<(), ShapeError> {
+        self.view_mut().try_assign_mask_from(mask, values)
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ArrayViewMut<'a, T, D> {
+    pub fn assign_mask(&mut self, mask: &Array<'_, bool, D>, val: T) {
+        unwrap_shape(self.try_assign_mask(mask, val))
+    }
+
+    pub fn try_assign_mask(&mut self, mask: &Array<'_, bool, D>, val: T) -> Result<(), ShapeError> {
+        check_same_shape(&self.shape, &mask.shape)?;
+
+        Zip::init(self).and(mask).for_each(|elem, selected| {
+            if *selected {
+                *elem = val.clone();
+            }
+        });
+
+        Ok(())
+    }
+
+    pub fn assign_mask_from(&mut self, mask: &Array<'_, bool, D>, values: &Array<'_, T, 1>) {
+        unwrap_shape(self.try_assign_mask_from(mask, values))
+    }
+
+    // Writes `values` in order into the elements where the mask is true, the
+    // reverse of `select_mask`.
+    pub fn try_assign_mask_from(
+        &mut self,
+        mask: &Array<'_, bool, D>,
+        values: &Array<'_, T, 1>,
+    ) -> Result<(), ShapeError> {
+        check_same_shape(&self.shape, &mask.shape)?;
+
+        let selected = mask.flat().filter(|selected| **selected).count();
+        if selected != values.len() {
+            return Err(ShapeError::ElementCountMismatch {
+                expected: selected,
+                found: values.len(),
+            });
+        }
+
+        let mut values = values.flat();
+        Zip::init(self).and(mask).for_each(|elem, selected| {
+            if *selected {
+                *elem = values.next().unwrap().clone();
+            }
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_mask() {
+        // 2-D array:
+        // 0.1 0.6 0.2
+        // 0.9 0.3 0.7
+        let array = Array::init(vec![0.1, 0.6, 0.2, 0.9, 0.3, 0.7], [2, 3]);
+
+        let selected = array.select_mask(&array.gt(0.5));
+        assert_eq!(selected.shape(), &[3]);
+        assert_eq!(
+            selected.flat().copied().collect::<Vec<f64>>(),
+            vec![0.6, 0.9, 0.7]
+        );
+
+        // masks follow the logical order of views
+        let transposed = array.t();
+        assert_eq!(
+            transposed
+                .select_mask(&transposed.gt(0.5))
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![0.9, 0.6, 0.7]
+        );
+
+        let mask = Array::init(vec![true, false, true], [3, 1]);
+        assert_eq!(
+            array.try_select_mask(&mask).err(),
+            Some(ShapeError::ShapeMismatch {
+                axis: 0,
+                left: 2,
+                right: 3
+            })
+        );
+    }
+
+    #[test]
+    fn assign_mask() {
+        // 2-D array:
+        // -1 2 -3
+        // 4 -5 6
+        let mut array = Array::init(vec![-1, 2, -3, 4, -5, 6], [2, 3]);
+
+        let negative = array.lt(0);
+        array.assign_mask(&negative, 0);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![0, 2, 0, 4, 0, 6]
+        );
+    }
+
+    #[test]
+    fn assign_mask_from() {
+        let mut array = Array::arange(0..6).into_shape([2, 3]);
+
+        // double the odd elements, writing them back in place
+        let odd = Array::init(vec![false, true, false, true, false, true], [2, 3]);
+        let doubled = array.select_mask(&odd) * 2;
+        array.assign_mask_from(&odd, &doubled);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 2, 2, 6, 4, 10]
+        );
+
+        assert_eq!(
+            array
+                .slice_mut(&[0..2, 1..3])
+                .try_assign_mask_from(&Array::full(true, [2, 2]), &doubled),
+            Err(ShapeError::ElementCountMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+}
@@ -1,63 +1,310 @@
 mod access;
+mod broadcast;
 mod calc;
+mod compare;
 mod iter;
+mod map;
+mod mask;
 mod ops;
+mod select;
+mod take;
 mod transformation;
-
-use std::borrow::Cow;
+mod view_mut;
+mod windows;
 
 use num_traits::{One, Zero};
 
+use crate::{
+    ndarray::error::unwrap_shape, ndarray::storage::Storage, AxisSlice, Order, ShapeError,
+};
+
+pub(crate) use calc::{mean_of, positions_of, prod_of, sum_of, var_of};
+pub(crate) use iter::{Iter, IterInner, IterMut, Walk};
+pub use view_mut::ArrayViewMut;
+
 #[derive(Debug, Clone, Copy)]
-struct IdxMap {
-    m: isize,
-    b: isize,
+pub(crate) struct IdxMap {
+    pub(crate) m: isize,
+    pub(crate) b: isize,
 }
 
 impl IdxMap {
-    fn init() -> Self {
+    pub(crate) fn init() -> Self {
         IdxMap { m: 1, b: 0 }
     }
 
-    fn map(&self, idx: usize) -> usize {
+    pub(crate) fn map(&self, idx: usize) -> usize {
         (self.m * (idx as isize) + self.b) as usize
     }
 
-    fn append_b(&mut self, b: isize) {
+    pub(crate) fn append_b(&mut self, b: isize) {
         self.b += self.m * b;
     }
 }
 
+pub(crate) fn fill_c_strides(shape: &[usize], strides: &mut [usize]) {
+    for axis in 0..shape.len() {
+        strides[axis] = shape[axis + 1..].iter().product();
+    }
+}
+
+pub(crate) fn fill_f_strides(shape: &[usize], strides: &mut [usize]) {
+    for axis in 0..shape.len() {
+        strides[axis] = shape[..axis].iter().product();
+    }
+}
+
+pub(crate) fn is_standard_layout(shape: &[usize], strides: &[usize], idx_maps: &[IdxMap]) -> bool {
+    is_contiguous_along(shape, strides, idx_maps, (0..shape.len()).rev())
+}
+
+pub(crate) fn is_f_layout(shape: &[usize], strides: &[usize], idx_maps: &[IdxMap]) -> bool {
+    is_contiguous_along(shape, strides, idx_maps, 0..shape.len())
+}
+
+// Axes are given from the fastest to the slowest varying one.
+fn is_contiguous_along<I: Iterator<Item = usize>>(
+    shape: &[usize],
+    strides: &[usize],
+    idx_maps: &[IdxMap],
+    axes: I,
+) -> bool {
+    let mut expected_stride = 1;
+
+    for axis in axes {
+        if shape[axis] == 1 {
+            continue;
+        }
+
+        if idx_maps[axis].m * strides[axis] as isize != expected_stride as isize {
+            return false;
+        }
+
+        expected_stride *= shape[axis];
+    }
+
+    true
+}
+
+pub(crate) fn offset_of(
+    offset: usize,
+    shape: &[usize],
+    strides: &[usize],
+    idx_maps: &[IdxMap],
+    indices: &[usize],
+) -> Option<usize> {
+    if indices.len() != shape.len()
+        || indices
+            .iter()
+            .enumerate()
+            .any(|(axis, idx)| *idx >= shape[axis])
+    {
+        return None;
+    }
+
+    let index = indices
+        .iter()
+        .enumerate()
+        .fold(offset, |acc, (axis, axis_index)| {
+            acc + idx_maps[axis].map(*axis_index) * strides[axis]
+        });
+
+    Some(index)
+}
+
+// Callers make sure that R + 1 == D.
+pub(crate) fn remove_axis<X: Copy, const D: usize, const R: usize>(
+    values: &[X; D],
+    axis: usize,
+) -> [X; R] {
+    let mut removed = [values[0]; R];
+
+    for (idx, value) in values
+        .iter()
+        .enumerate()
+        .filter(|(value_axis, _)| *value_axis != axis)
+        .map(|(_, value)| value)
+        .enumerate()
+    {
+        removed[idx] = *value;
+    }
+
+    removed
+}
+
+// Shape that both shapes broadcast to, lining them up from their last axis:
+// the lengths of an axis must either match or one of them must be one.
+pub(crate) fn broadcast_shape(left: &[usize], right: &[usize]) -> Result<Vec<usize>, ShapeError> {
+    let ndim = left.len().max(right.len());
+    let mut shape = vec![0; ndim];
+
+    for (axis, len) in shape.iter_mut().enumerate() {
+        let left_len = (axis + left.len())
+            .checked_sub(ndim)
+            .map_or(1, |axis| left[axis]);
+        let right_len = (axis + right.len())
+            .checked_sub(ndim)
+            .map_or(1, |axis| right[axis]);
+
+        *len = match (left_len, right_len) {
+            (left_len, right_len) if left_len == right_len => left_len,
+            (1, right_len) => right_len,
+            (left_len, 1) => left_len,
+            _ => {
+                return Err(ShapeError::IncompatibleShapes {
+                    left: left.to_vec(),
+                    right: right.to_vec(),
+                })
+            }
+        };
+    }
+
+    Ok(shape)
+}
+
+// Layout of an array viewed with the larger shape `to`. New leading axes and
+// axes of length one repeat their element with a zero stride, so the element
+// they point at is folded into the returned offset.
+pub(crate) fn broadcast_layout(
+    offset: usize,
+    shape: &[usize],
+    strides: &[usize],
+    idx_maps: &[IdxMap],
+    to: &[usize],
+    to_strides: &mut [usize],
+    to_idx_maps: &mut [IdxMap],
+) -> Result<usize, ShapeError> {
+    let incompatible = || ShapeError::IncompatibleShapes {
+        left: shape.to_vec(),
+        right: to.to_vec(),
+    };
+
+    let lead = to.len().checked_sub(shape.len()).ok_or_else(incompatible)?;
+    let mut offset = offset;
+
+    for axis in 0..to.len() {
+        to_strides[axis] = 0;
+        to_idx_maps[axis] = IdxMap::init();
+
+        if axis < lead {
+            continue;
+        }
+
+        let from = axis - lead;
+        if shape[from] == to[axis] {
+            to_strides[axis] = strides[from];
+            to_idx_maps[axis] = idx_maps[from];
+        } else if shape[from] == 1 {
+            offset += idx_maps[from].map(0) * strides[from];
+        } else {
+            return Err(incompatible());
+        }
+    }
+
+    Ok(offset)
+}
+
+// Whether a layout reads the same element through more than one index, as a
+// broadcast view does. Such a layout gets elements of its own before anything
+// writes through it, or one write would show up at several indices.
+pub(crate) fn is_broadcast(shape: &[usize], strides: &[usize]) -> bool {
+    shape
+        .iter()
+        .zip(strides)
+        .any(|(len, stride)| *len > 1 && *stride == 0)
+}
+
+// Whether the elements of a layout are exactly the `buffer_len` elements of
+// its buffer, in any order.
+pub(crate) fn covers_buffer(shape: &[usize], strides: &[usize], buffer_len: usize) -> bool {
+    shape.iter().product::<usize>() == buffer_len && !is_broadcast(shape, strides)
+}
+
+pub(crate) fn slice_layout<S: Clone + Into<AxisSlice>>(
+    shape: &mut [usize],
+    idx_maps: &mut [IdxMap],
+    slice: &[S],
+) -> Result<(), ShapeError> {
+    for (axis, axis_slice) in slice.iter().enumerate() {
+        let AxisSlice { start, end, step } = axis_slice.clone().into();
+        let end = end.unwrap_or(shape[axis]);
+
+        if start > end || end > shape[axis] {
+            return Err(ShapeError::RangeOutOfBounds {
+                axis,
+                start,
+                end,
+                len: shape[axis],
+            });
+        }
+
+        if step == 0 {
+            return Err(ShapeError::ZeroStep { axis });
+        }
+
+        let len = end - start;
+
+        if step > 0 || len == 0 {
+            idx_maps[axis].append_b(start as isize);
+        } else {
+            idx_maps[axis].append_b(end as isize - 1);
+        }
+
+        idx_maps[axis].m *= step;
+        shape[axis] = len.div_ceil(step.unsigned_abs());
+    }
+
+    Ok(())
+}
+
 pub struct Array<'a, T: Clone, const D: usize> {
-    vec: Cow<'a, [T]>,
-    shape: [usize; D],
-    strides: [usize; D],
-    idx_maps: [IdxMap; D],
+    pub(crate) vec: Storage<'a, T>,
+    pub(crate) shape: [usize; D],
+    pub(crate) strides: [usize; D],
+    pub(crate) idx_maps: [IdxMap; D],
+    pub(crate) offset: usize,
 }
 
 impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
     pub fn init(vec: Vec<T>, shape: [usize; D]) -> Self {
+        unwrap_shape(Array::try_init(vec, shape))
+    }
+
+    pub fn try_init(vec: Vec<T>, shape: [usize; D]) -> Result<Self, ShapeError> {
+        Array::try_init_with_order(vec, shape, Order::C)
+    }
+
+    pub fn init_with_order(vec: Vec<T>, shape: [usize; D], order: Order) -> Self {
+        unwrap_shape(Array::try_init_with_order(vec, shape, order))
+    }
+
+    pub fn try_init_with_order(
+        vec: Vec<T>,
+        shape: [usize; D],
+        order: Order,
+    ) -> Result<Self, ShapeError> {
         let elem_count: usize = shape.iter().product();
 
         if elem_count != vec.len() {
-            panic!(
-                "Number of elements in vec is not equal to dimension specification: {} != {}",
-                vec.len(),
-                elem_count
-            );
+            return Err(ShapeError::ElementCountMismatch {
+                expected: elem_count,
+                found: vec.len(),
+            });
         }
 
         let mut strides = [0; D];
-        for axis in 0..D {
-            strides[axis] = shape[axis + 1..].iter().fold(1, |acc, v| acc * v);
+        match order {
+            Order::C => fill_c_strides(&shape, &mut strides),
+            Order::F => fill_f_strides(&shape, &mut strides),
         }
 
-        Array {
-            vec: Cow::from(vec),
+        Ok(Array {
+            vec: Storage::from(vec),
             shape,
             strides,
             idx_maps: [IdxMap::init(); D],
-        }
+            offset: 0,
+        })
     }
 
     pub fn shape(&self) -> &[usize; D] {
@@ -68,12 +315,68 @@ impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
         &self.strides
     }
 
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Elements of a standard layout array are laid out contiguously in row major order,
+    // so reshaping it does not copy.
+    pub fn is_standard_layout(&self) -> bool {
+        is_standard_layout(&self.shape, &self.strides, &self.idx_maps)
+    }
+
+    pub fn is_c_contiguous(&self) -> bool {
+        self.is_standard_layout()
+    }
+
+    pub fn is_f_contiguous(&self) -> bool {
+        is_f_layout(&self.shape, &self.strides, &self.idx_maps)
+    }
+
+    pub fn shares_buffer_with<'b, const S: usize>(&self, other: &Array<'b, T, S>) -> bool {
+        self.vec.as_ptr() == other.vec.as_ptr()
+    }
+
     pub fn full(val: T, shape: [usize; D]) -> Array<'a, T, D> {
         Array::init(vec![val; shape.iter().product()], shape)
     }
 
     pub fn full_like<'b, U: Clone>(val: T, array: &Array<'b, U, D>) -> Array<'a, T, D> {
-        Array::full(val, array.shape().clone())
+        Array::full(val, *array.shape())
+    }
+
+    pub fn into_shared<'b>(self) -> Array<'b, T, D> {
+        Array {
+            vec: self.vec.into_shared(),
+            shape: self.shape,
+            strides: self.strides,
+            idx_maps: self.idx_maps,
+            offset: self.offset,
+        }
+    }
+
+    pub fn to_shared<'b>(&self) -> Array<'b, T, D> {
+        self.view().into_shared()
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.vec.is_shared()
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Clone for Array<'a, T, D> {
+    fn clone(&self) -> Self {
+        Array {
+            vec: self.vec.clone(),
+            shape: self.shape,
+            strides: self.strides,
+            idx_maps: self.idx_maps,
+            offset: self.offset,
+        }
     }
 }
 
@@ -91,8 +394,12 @@ impl<'a, T: Clone + Zero, const D: usize> Array<'a, T, D> {
         Array::init(vec![T::zero(); shape.iter().product()], shape)
     }
 
+    pub fn zeros_f(shape: [usize; D]) -> Self {
+        Array::init_with_order(vec![T::zero(); shape.iter().product()], shape, Order::F)
+    }
+
     pub fn zeros_like<'b, U: Clone>(array: &Array<'b, U, D>) -> Array<'a, T, D> {
-        Array::zeros(array.shape().clone())
+        Array::zeros(*array.shape())
     }
 }
 
@@ -102,7 +409,7 @@ impl<'a, T: Clone + One, const D: usize> Array<'a, T, D> {
     }
 
     pub fn ones_like<'b, U: Clone>(array: &Array<'b, U, D>) -> Array<'a, T, D> {
-        Array::ones(array.shape().clone())
+        Array::ones(*array.shape())
     }
 }
 
@@ -132,7 +439,7 @@ mod tests {
 
     #[test]
     fn zeros_like() {
-        let array = Array::arange(0..8).reshape([2, 4]);
+        let array = Array::arange(0..8).into_shape([2, 4]);
 
         let zeros_like = Array::zeros_like(&array);
 
@@ -154,7 +461,7 @@ mod tests {
 
     #[test]
     fn ones_like() {
-        let array = Array::arange(0..8).reshape([2, 4]);
+        let array = Array::arange(0..8).into_shape([2, 4]);
 
         let ones_like = Array::ones_like(&array);
 
@@ -176,7 +483,7 @@ mod tests {
 
     #[test]
     fn full_like() {
-        let array = Array::arange(0..8).reshape([2, 4]);
+        let array = Array::arange(0..8).into_shape([2, 4]);
 
         let full_like = Array::full_like(10, &array);
 
@@ -185,4 +492,95 @@ mod tests {
             vec![10, 10, 10, 10, 10, 10, 10, 10]
         )
     }
+
+    #[test]
+    fn try_init() {
+        assert!(Array::try_init(vec![1, 2, 3, 4], [2, 2]).is_ok());
+
+        assert_eq!(
+            Array::try_init(vec![1, 2, 3], [2, 2]).err(),
+            Some(ShapeError::ElementCountMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn shared_views() {
+        let array = Array::arange(0..6).into_shape([2, 3]).into_shared();
+
+        // views of a shared array refer to the same buffer and can outlive the borrow
+        let flipped = array.flip(0).into_shared();
+        let column = array.slice(&[0..2, 1..2]).into_shared();
+
+        assert!(flipped.is_shared() && column.is_shared());
+        assert_eq!(flipped.vec.as_ptr(), array.vec.as_ptr());
+        assert_eq!(column.vec.as_ptr(), array.vec.as_ptr());
+        assert_eq!(column.flat().copied().collect::<Vec<usize>>(), vec![1, 4]);
+    }
+
+    #[test]
+    fn shared_views_cross_threads() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let array = Array::arange(0..6).into_shape([2, 3]).into_shared();
+        let row = array.slice(&[1..2, 0..3]).into_shared();
+
+        assert_send_sync(&row);
+
+        let sum = std::thread::spawn(move || row.sum()).join().unwrap();
+
+        assert_eq!(sum, 12);
+    }
+
+    #[test]
+    fn shared_copy_on_write() {
+        let array = Array::arange(0..4).into_shape([2, 2]).into_shared();
+        let mut view = array.t().into_shared();
+
+        // the buffer is still referenced by `array` so it gets copied
+        view[[0, 1]] = 20;
+
+        assert_ne!(view.vec.as_ptr(), array.vec.as_ptr());
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 20, 1, 3]
+        );
+
+        // the buffer is not referenced anymore so it is written in place
+        let ptr = view.vec.as_ptr();
+        view[[1, 1]] = 30;
+
+        assert_eq!(view.vec.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn init_with_order() {
+        // 2-D array stored in column major order:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init_with_order(vec![1, 4, 2, 5, 3, 6], [2, 3], Order::F);
+
+        assert_eq!(array.strides(), &[1, 2]);
+        assert!(array.is_f_contiguous());
+        assert!(!array.is_c_contiguous());
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn zeros_f() {
+        let array: Array<f64, 3> = Array::zeros_f([2, 3, 4]);
+
+        assert_eq!(array.strides(), &[1, 2, 6]);
+        assert!(array.is_f_contiguous());
+        assert!(array.t().is_c_contiguous());
+    }
 }
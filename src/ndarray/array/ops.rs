@@ -1,154 +1,195 @@
-use std::{
-    borrow::Cow,
-    ops::{Add, Div, Mul, Neg, Sub},
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
-use crate::Array;
+use super::broadcast::RankFits;
+use crate::{ndarray::error::unwrap_shape, Array, ArrayViewMut, ScalarOperand, ShapeError};
 
 impl<'a, T: Clone + Neg<Output = T>, const D: usize> Neg for Array<'a, T, D> {
     type Output = Array<'a, T, D>;
 
-    fn neg(mut self) -> Self::Output {
-        for idx in 0..self.vec.len() {
-            self.vec.to_mut()[idx] = -(self.vec[idx].clone());
-        }
-
-        self
+    fn neg(self) -> Self::Output {
+        self.map_into(|val| -val)
     }
 }
 
-impl<'a, 'b, T: Clone + Add<Output = T>, const D: usize> Add<&Array<'b, T, D>>
-    for &Array<'a, T, D>
-{
+impl<'a, T: Clone + Not<Output = T>, const D: usize> Not for &Array<'a, T, D> {
     type Output = Array<'a, T, D>;
 
-    fn add(self, rhs: &Array<'b, T, D>) -> Self::Output {
-        (0..D).for_each(|axis| {
-            if self.shape[axis] != rhs.shape[axis] {
-                panic!(
-                    "Shape of array at axis: {} is not equall to right hand side: {} != {}",
-                    axis, self.shape[axis], rhs.shape[axis]
-                )
-            }
-        });
-
-        let sum_vec = self
-            .flat()
-            .zip(rhs.flat())
-            .map(|(v1, v2)| v1.clone() + v2.clone())
-            .collect();
-
-        Array::init(sum_vec, self.shape().clone())
+    fn not(self) -> Self::Output {
+        self.map(|val| !val.clone())
     }
 }
 
-impl<'a, 'b, T: Clone + Add<Output = T>, const D: usize> Add<Array<'b, T, D>> for Array<'a, T, D> {
+impl<'a, T: Clone + Not<Output = T>, const D: usize> Not for Array<'a, T, D> {
     type Output = Array<'a, T, D>;
 
-    fn add(self, rhs: Array<'b, T, D>) -> Self::Output {
-        &self + &rhs
+    fn not(self) -> Self::Output {
+        !&self
     }
 }
 
-impl<'a, 'b, T: Clone + Sub<Output = T>, const D: usize> Sub<&Array<'b, T, D>>
-    for &Array<'a, T, D>
-{
-    type Output = Array<'a, T, D>;
-
-    fn sub(self, rhs: &Array<'b, T, D>) -> Self::Output {
-        (0..D).for_each(|axis| {
-            if self.shape[axis] != rhs.shape[axis] {
-                panic!(
-                    "Shape of array at axis: {} is not equall to right hand side: {} != {}",
-                    axis, self.shape[axis], rhs.shape[axis]
-                )
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $checked:ident, $op:tt) => {
+        impl<'a, T: Clone + $trait<Output = T>, const D: usize> Array<'a, T, D> {
+            /// Broadcasts both operands together. The result has the rank of
+            /// `self`, so a `rhs` with more axes than `self` is an error:
+            /// broadcast `self` to the higher rank first.
+            pub fn $checked<'b, const R: usize>(
+                &self,
+                rhs: &Array<'b, T, R>,
+            ) -> Result<Array<'a, T, D>, ShapeError> {
+                self.try_zip_with(rhs, |v1, v2| v1.clone() $op v2.clone())
             }
-        });
+        }
 
-        let sum_vec = self
-            .flat()
-            .zip(rhs.flat())
-            .map(|(v1, v2)| v1.clone() - v2.clone())
-            .collect();
+        /// Broadcasts both operands together. The result has the rank of the
+        /// left hand side, so a right hand side with more axes does not
+        /// compile: broadcast the left hand side to the higher rank first.
+        impl<'a, 'b, T: Clone + $trait<Output = T>, const D: usize, const R: usize>
+            $trait<&Array<'b, T, R>> for &Array<'a, T, D>
+        {
+            type Output = Array<'a, T, D>;
 
-        Array::init(sum_vec, self.shape().clone())
-    }
-}
+            fn $method(self, rhs: &Array<'b, T, R>) -> Self::Output {
+                let () = RankFits::<D, R>::CHECK;
 
-impl<'a, 'b, T: Clone + Sub<Output = T>, const D: usize> Sub<Array<'b, T, D>> for Array<'a, T, D> {
-    type Output = Array<'a, T, D>;
+                unwrap_shape(self.$checked(rhs))
+            }
+        }
 
-    fn sub(self, rhs: Array<'b, T, D>) -> Self::Output {
-        &self - &rhs
-    }
+        forward_binary_op!(Array [D] [R], $trait, $method, $op);
+    };
 }
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Mul<U, Output = O>, const D: usize> Mul<U>
-    for &Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
-
-    fn mul(self, rhs: U) -> Self::Output {
-        let vec: Vec<O> = self
-            .vec
-            .iter()
-            .map(|val| val.clone() * rhs.clone())
-            .collect();
-
-        Array {
-            vec: Cow::from(vec),
-            shape: self.shape().clone(),
-            strides: self.strides().clone(),
-            idx_maps: self.idx_maps.clone(),
+impl_binary_op!(Add, add, checked_add, +);
+impl_binary_op!(Sub, sub, checked_sub, -);
+impl_binary_op!(Mul, mul, checked_mul, *);
+impl_binary_op!(Div, div, checked_div, /);
+impl_binary_op!(Rem, rem, checked_rem, %);
+impl_binary_op!(BitAnd, bitand, checked_bitand, &);
+impl_binary_op!(BitOr, bitor, checked_bitor, |);
+impl_binary_op!(BitXor, bitxor, checked_bitxor, ^);
+impl_binary_op!(Shl, shl, checked_shl, <<);
+impl_binary_op!(Shr, shr, checked_shr, >>);
+
+impl_scalar_op!(Array [D], Add, add, +);
+impl_scalar_op!(Array [D], Sub, sub, -);
+impl_scalar_op!(Array [D], Mul, mul, *);
+impl_scalar_op!(Array [D], Div, div, /);
+impl_scalar_op!(Array [D], Rem, rem, %);
+impl_scalar_op!(Array [D], BitAnd, bitand, &);
+impl_scalar_op!(Array [D], BitOr, bitor, |);
+impl_scalar_op!(Array [D], BitXor, bitxor, ^);
+impl_scalar_op!(Array [D], Shl, shl, <<);
+impl_scalar_op!(Array [D], Shr, shr, >>);
+
+// A scalar on the left hand side can not be a type parameter of a foreign
+// trait impl, so every primitive gets impls of its own.
+macro_rules! impl_scalar_lhs_op {
+    ($($scalar:ty),*) => {
+        $(
+            impl_scalar_lhs_op!(@op $scalar, Add, add, +);
+            impl_scalar_lhs_op!(@op $scalar, Sub, sub, -);
+            impl_scalar_lhs_op!(@op $scalar, Mul, mul, *);
+            impl_scalar_lhs_op!(@op $scalar, Div, div, /);
+            impl_scalar_lhs_op!(@op $scalar, Rem, rem, %);
+        )*
+    };
+    (@op $scalar:ty, $trait:ident, $method:ident, $op:tt) => {
+        impl<'a, const D: usize> $trait<&Array<'a, $scalar, D>> for $scalar {
+            type Output = Array<'a, $scalar, D>;
+
+            fn $method(self, rhs: &Array<'a, $scalar, D>) -> Self::Output {
+                rhs.map(|val| self $op *val)
+            }
         }
-    }
-}
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Mul<U, Output = O>, const D: usize> Mul<U>
-    for Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
+        impl<'a, const D: usize> $trait<Array<'a, $scalar, D>> for $scalar {
+            type Output = Array<'a, $scalar, D>;
 
-    fn mul(self, rhs: U) -> Self::Output {
-        (&self).mul(rhs)
-    }
+            fn $method(self, rhs: Array<'a, $scalar, D>) -> Self::Output {
+                self $op &rhs
+            }
+        }
+    };
 }
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Div<U, Output = O>, const D: usize> Div<U>
-    for &Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
-
-    fn div(self, rhs: U) -> Self::Output {
-        let vec: Vec<O> = self
-            .vec
-            .iter()
-            .map(|val| val.clone() / rhs.clone())
-            .collect();
-
-        Array {
-            vec: Cow::from(vec),
-            shape: self.shape().clone(),
-            strides: self.strides().clone(),
-            idx_maps: self.idx_maps.clone(),
+impl_scalar_lhs_op!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+// Compound assignment writes through the elements in place. An array on the
+// right hand side is broadcast to the shape of the left hand side.
+macro_rules! impl_assign_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<'a, 'b, T: Clone + $trait<T>, const D: usize, const R: usize> $trait<&Array<'b, T, R>>
+            for ArrayViewMut<'a, T, D>
+        {
+            fn $method(&mut self, rhs: &Array<'b, T, R>) {
+                let () = RankFits::<D, R>::CHECK;
+
+                unwrap_shape(self.broadcast_zip_mut(rhs, |elem, val| *elem $op val.clone()))
+            }
         }
-    }
-}
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Div<U, Output = O>, const D: usize> Div<U>
-    for Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
+        impl<'a, 'b, T: Clone + $trait<T>, const D: usize, const R: usize> $trait<Array<'b, T, R>>
+            for ArrayViewMut<'a, T, D>
+        {
+            fn $method(&mut self, rhs: Array<'b, T, R>) {
+                *self $op &rhs
+            }
+        }
 
-    fn div(self, rhs: U) -> Self::Output {
-        (&self).div(rhs)
-    }
+        impl<'a, 'b, T: Clone + $trait<T>, const D: usize, const R: usize> $trait<&Array<'b, T, R>>
+            for Array<'a, T, D>
+        {
+            fn $method(&mut self, rhs: &Array<'b, T, R>) {
+                let mut view = self.view_mut();
+                view $op rhs;
+            }
+        }
+
+        impl<'a, 'b, T: Clone + $trait<T>, const D: usize, const R: usize> $trait<Array<'b, T, R>>
+            for Array<'a, T, D>
+        {
+            fn $method(&mut self, rhs: Array<'b, T, R>) {
+                *self $op &rhs
+            }
+        }
+
+        impl<'a, U: ScalarOperand, T: Clone + $trait<U>, const D: usize> $trait<U>
+            for ArrayViewMut<'a, T, D>
+        {
+            fn $method(&mut self, rhs: U) {
+                self.map_inplace(|elem| *elem $op rhs.clone());
+            }
+        }
+
+        impl<'a, U: ScalarOperand, T: Clone + $trait<U>, const D: usize> $trait<U>
+            for Array<'a, T, D>
+        {
+            fn $method(&mut self, rhs: U) {
+                self.map_inplace(|elem| *elem $op rhs.clone());
+            }
+        }
+    };
 }
 
+impl_assign_op!(AddAssign, add_assign, +=);
+impl_assign_op!(SubAssign, sub_assign, -=);
+impl_assign_op!(MulAssign, mul_assign, *=);
+impl_assign_op!(DivAssign, div_assign, /=);
+impl_assign_op!(RemAssign, rem_assign, %=);
+impl_assign_op!(BitAndAssign, bitand_assign, &=);
+impl_assign_op!(BitOrAssign, bitor_assign, |=);
+impl_assign_op!(BitXorAssign, bitxor_assign, ^=);
+impl_assign_op!(ShlAssign, shl_assign, <<=);
+impl_assign_op!(ShrAssign, shr_assign, >>=);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s;
 
     #[test]
     fn neg() {
@@ -160,6 +201,16 @@ mod tests {
             neg_array.flat().copied().collect::<Vec<i32>>(),
             vec![-1, -2, -3, -4, -5, -6]
         );
+
+        // the element outside of the view is never negated
+        let array = Array::init(vec![i32::MIN, 2, 4], [3]);
+        assert_eq!(
+            (-array.slice(&s![1..3]))
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![-2, -4]
+        );
     }
 
     #[test]
@@ -211,4 +262,280 @@ mod tests {
             vec![1, 2, 3, 4, 5, 6]
         );
     }
+
+    #[test]
+    fn checked_add_sub() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array2 = Array::init(vec![6, 5, 4, 3, 2, 1], [2, 3]);
+        let array3 = Array::init(vec![1, 2, 3, 4, 5, 6], [3, 2]);
+
+        assert_eq!(
+            array
+                .checked_add(&array2)
+                .unwrap()
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![7, 7, 7, 7, 7, 7]
+        );
+        assert_eq!(
+            array.checked_sub(&array3).err(),
+            Some(ShapeError::IncompatibleShapes {
+                left: vec![2, 3],
+                right: vec![3, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn broadcast_add_sub() {
+        // [3, 1] + [1, 4]:
+        // 0    0 1 2 3    0 1 2 3
+        // 1 +          =  1 2 3 4
+        // 2               2 3 4 5
+        let column = Array::arange(0..3).into_shape([3, 1]);
+        let row = Array::arange(0..4).into_shape([1, 4]);
+
+        let sum = &column + &row;
+        assert_eq!(sum.shape(), &[3, 4]);
+        assert_eq!(
+            sum.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 1, 2, 3, 4, 2, 3, 4, 5]
+        );
+
+        // a row vector of lower rank is subtracted from every row
+        let vector = Array::arange(0..4);
+        let diff = sum - vector;
+        assert_eq!(
+            diff.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2]
+        );
+
+        // a left hand side of lower rank is broadcast to the higher rank first
+        let lower: Array<i32, 1> = Array::init(vec![10, 20], [2]);
+        let higher = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        assert_eq!(
+            (&lower.broadcast([2, 2]) - &higher)
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![9, 18, 7, 16]
+        );
+
+        // the broadcast shape can not have more axes than the left hand side,
+        // but swapping the operands of a commutative op gives the same result
+        let matrix = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        assert_eq!(
+            (&matrix + &Array::init(vec![1, 2], [2]))
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![2, 4, 4, 6]
+        );
+        assert_eq!(
+            Array::init(vec![1, 2], [2]).checked_add(&matrix).err(),
+            Some(ShapeError::BroadcastRankMismatch {
+                rank: 1,
+                shape: vec![2, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn mul_div_rem() {
+        // 2-D arrays:
+        // 1 2 3    2 2 2
+        // 4 5 6    3 3 3
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array2 = Array::init(vec![2, 2, 2, 3, 3, 3], [2, 3]);
+
+        assert_eq!(
+            (&array * &array2).flat().copied().collect::<Vec<i32>>(),
+            vec![2, 4, 6, 12, 15, 18]
+        );
+        assert_eq!(
+            (&array / array2.view())
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![0, 1, 1, 1, 1, 2]
+        );
+        assert_eq!(
+            (array.view() % &array2)
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![1, 0, 1, 1, 2, 0]
+        );
+
+        // the column is broadcast across the rows
+        let column = Array::init(vec![10, 100], [2, 1]);
+        assert_eq!(
+            (array * column).flat().copied().collect::<Vec<i32>>(),
+            vec![10, 20, 30, 400, 500, 600]
+        );
+    }
+
+    #[test]
+    fn checked_mul() {
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
+        let array2 = Array::init(vec![1.0, 2.0, 3.0], [3]);
+
+        assert_eq!(
+            array.checked_mul(&array2).err(),
+            Some(ShapeError::IncompatibleShapes {
+                left: vec![2, 2],
+                right: vec![3]
+            })
+        );
+        assert_eq!(
+            (&array % 3.0).flat().copied().collect::<Vec<f64>>(),
+            vec![1.0, 2.0, 0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn assign_ops() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let mut array = Array::init(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [2, 3]);
+        let buffer = array.vec.as_ptr();
+
+        array += &Array::init(vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0], [2, 3]);
+        array *= 2.0;
+        // the row is broadcast to every row
+        array -= Array::init(vec![4.0, 6.0, 8.0], [3]);
+        array /= 2.0;
+        array %= 3.0;
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<f64>>(),
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]
+        );
+        assert_eq!(array.vec.as_ptr(), buffer);
+    }
+
+    #[test]
+    fn assign_ops_on_views() {
+        let mut array = Array::arange(0..6).into_shape([2, 3]);
+
+        // 0 1 2      0 10 20
+        // 3 4 5  ->  3 40 50
+        let mut view = array.slice_mut(&[0..2, 1..3]);
+        view *= 10;
+        view += &Array::init(vec![1, 2], [2, 1]);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 11, 21, 3, 42, 52]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can not be broadcast together")]
+    fn assign_ops_incompatible() {
+        let mut array: Array<usize, 2> = Array::zeros([2, 3]);
+
+        array += Array::ones([2]);
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let array: Array<f64, 2> = Array::init(vec![1.0, 2.0, 4.0, 8.0], [2, 2]);
+
+        assert_eq!(
+            (&array + 2.0).flat().copied().collect::<Vec<f64>>(),
+            vec![3.0, 4.0, 6.0, 10.0]
+        );
+        assert_eq!(
+            (&array - 1.5).flat().copied().collect::<Vec<f64>>(),
+            vec![-0.5, 0.5, 2.5, 6.5]
+        );
+        assert_eq!(
+            (2.0 * &array).flat().copied().collect::<Vec<f64>>(),
+            vec![2.0, 4.0, 8.0, 16.0]
+        );
+        assert_eq!(
+            (1.0 / array).flat().copied().collect::<Vec<f64>>(),
+            vec![1.0, 0.5, 0.25, 0.125]
+        );
+
+        let array: Array<i32, 2> = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        assert_eq!(
+            (10 - array.t()).flat().copied().collect::<Vec<i32>>(),
+            vec![9, 7, 8, 6]
+        );
+    }
+
+    #[test]
+    fn scalar_ops_on_views() {
+        // 2-D array:
+        // 0 1 2
+        // 0 3 4
+        let array: Array<i32, 2> = Array::init(vec![0, 1, 2, 0, 3, 4], [2, 3]);
+
+        // the zeros outside of the view are never divided by
+        let view = array.slice(&[0..2, 1..3]);
+        let quotient = 12 / &view;
+
+        assert!(quotient.is_standard_layout());
+        assert_eq!(
+            quotient.flat().copied().collect::<Vec<i32>>(),
+            vec![12, 6, 4, 3]
+        );
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        // boolean masks:
+        // T T F    T F F
+        // F T F    T T T
+        let mask = Array::init(vec![true, true, false, false, true, false], [2, 3]);
+        let mask2 = Array::init(vec![true, false, false, true, true, true], [2, 3]);
+
+        assert_eq!(
+            (&mask & &mask2).flat().copied().collect::<Vec<bool>>(),
+            vec![true, false, false, false, true, false]
+        );
+        assert_eq!(
+            (&mask | &mask2).flat().copied().collect::<Vec<bool>>(),
+            vec![true, true, false, true, true, true]
+        );
+        assert_eq!(
+            (&mask ^ true).flat().copied().collect::<Vec<bool>>(),
+            vec![false, false, true, true, false, true]
+        );
+        assert_eq!(
+            (!mask).flat().copied().collect::<Vec<bool>>(),
+            vec![false, false, true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn shift_ops() {
+        let flags: Array<u32, 2> = Array::init(vec![1, 2, 4, 8], [2, 2]);
+        let shifts: Array<u32, 1> = Array::init(vec![1, 2], [2]);
+
+        assert_eq!(
+            (&flags << &shifts).flat().copied().collect::<Vec<u32>>(),
+            vec![2, 8, 8, 32]
+        );
+        assert_eq!(
+            (&flags >> 1).flat().copied().collect::<Vec<u32>>(),
+            vec![0, 1, 2, 4]
+        );
+
+        let mut flags = flags;
+        flags |= 16;
+        flags &= &Array::init(vec![17, 31], [2]);
+        flags ^= 1;
+        flags <<= 1;
+        flags >>= &shifts;
+
+        assert_eq!(
+            flags.flat().copied().collect::<Vec<u32>>(),
+            vec![16, 9, 17, 12]
+        );
+    }
 }
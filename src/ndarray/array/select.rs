@@ -0,0 +1,162 @@
+use super::{broadcast::fixed_rank, broadcast_shape};
+use crate::{ndarray::error::unwrap_shape, Array, IntoOperand, ShapeError, Zip};
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    /// Picks the element of `x` where `condition` is true and the element of
+    /// `y` where it is false.
+    ///
+    /// `x` and `y` are arrays or scalars. The condition and both operands are
+    /// broadcast together, and the result has the rank of the condition.
+    ///
+    /// Panics when the shapes can not be broadcast together, see
+    /// [`Array::try_where_`].
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let array: Array<i32, 2> = Array::init(vec![-2, -1, 0, 1, 2, 3], [2, 3]);
+    ///
+    /// // relu: the element where it is positive, zero elsewhere
+    /// let relu = Array::where_(&array.gt(0), &array, 0);
+    /// assert_eq!(
+    ///     relu.flat().copied().collect::<Vec<i32>>(),
+    ///     vec![0, 0, 0, 1, 2, 3]
+    /// );
+    /// ```
+    pub fn where_<'b, 'c, X, Y, const R: usize, const S: usize>(
+        condition: &Array<'_, bool, D>,
+        x: X,
+        y: Y,
+    ) -> Array<'a, T, D>
+    where
+        T: 'b + 'c,
+        X: IntoOperand<'b, T, R>,
+        Y: IntoOperand<'c, T, S>,
+    {
+        unwrap_shape(Array::try_where_(condition, x, y))
+    }
+
+    /// Fallible version of [`Array::where_`].
+    ///
+    /// Fails with [`ShapeError::IncompatibleShapes`] when the condition and the
+    /// operands can not be broadcast together, and with
+    /// [`ShapeError::BroadcastRankMismatch`] when their broadcast shape has more
+    /// axes than the condition.
+    pub fn try_where_<'b, 'c, X, Y, const R: usize, const S: usize>(
+        condition: &Array<'_, bool, D>,
+        x: X,
+        y: Y,
+    ) -> Result<Array<'a, T, D>, ShapeError>
+    where
+        T: 'b + 'c,
+        X: IntoOperand<'b, T, R>,
+        Y: IntoOperand<'c, T, S>,
+    {
+        let x = x.into_operand();
+        let y = y.into_operand();
+
+        let shape = broadcast_shape(condition.shape(), x.shape())?;
+        let shape = fixed_rank(broadcast_shape(&shape, y.shape())?)?;
+
+        let condition = condition.try_broadcast(shape)?;
+        let x = x.try_broadcast(shape)?;
+        let y = y.try_broadcast(shape)?;
+
+        Ok(Zip::init(&condition)
+            .and(&x)
+            .and(&y)
+            .map_collect(|selected, x, y| if *selected { x.clone() } else { y.clone() }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn where_() {
+        // 2-D array:
+        // -2 -1 0
+        // 1  2  3
+        let array: Array<i32, 2> = Array::init(vec![-2, -1, 0, 1, 2, 3], [2, 3]);
+
+        // piecewise: the square of negative elements, the element otherwise
+        let squares = &array * &array;
+        let piecewise = Array::where_(&array.lt(0), &squares, &array);
+        assert_eq!(
+            piecewise.flat().copied().collect::<Vec<i32>>(),
+            vec![4, 1, 0, 1, 2, 3]
+        );
+
+        // relu with a scalar
+        let relu = Array::where_(&array.gt(0), &array, 0);
+        assert_eq!(
+            relu.flat().copied().collect::<Vec<i32>>(),
+            vec![0, 0, 0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn where_broadcast() {
+        // a column condition picks between a row and a scalar:
+        // T    1 2 3    1 2 3
+        // F            -1 -1 -1
+        let condition = Array::init(vec![true, false], [2, 1]);
+        let row = Array::init(vec![1, 2, 3], [3]);
+
+        let picked = Array::where_(&condition, &row, -1);
+        assert_eq!(picked.shape(), &[2, 3]);
+        assert_eq!(
+            picked.flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, -1, -1, -1]
+        );
+
+        let column = Array::init(vec![1, 2, 3], [3, 1]);
+        assert_eq!(
+            Array::try_where_(&condition, &column, 0).err(),
+            Some(ShapeError::IncompatibleShapes {
+                left: vec![2, 1],
+                right: vec![3, 1]
+            })
+        );
+    }
+
+    #[test]
+    fn where_condition_rank() {
+        // the result has the rank of the condition, so operands of a higher
+        // rank need a condition of that rank too
+        let condition = Array::init(vec![true, false], [2]);
+        let matrix = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        assert_eq!(
+            Array::try_where_(&condition, &matrix, 0).err(),
+            Some(ShapeError::BroadcastRankMismatch {
+                rank: 1,
+                shape: vec![2, 2]
+            })
+        );
+
+        let picked = Array::where_(&condition.reshape([1, 2]), &matrix, 0);
+        assert_eq!(
+            picked.flat().copied().collect::<Vec<i32>>(),
+            vec![1, 0, 3, 0]
+        );
+    }
+
+    #[test]
+    fn where_broadcast_all() {
+        // condition, x and y all have different shapes:
+        // T F T    10    1 2 3    10 2 10
+        //          20             20 2 20
+        let condition = Array::init(vec![true, false, true], [1, 3]);
+        let x = Array::init(vec![10, 20], [2, 1]);
+        let y = Array::init(vec![1, 2, 3], [3]);
+
+        let picked = Array::where_(&condition, &x, &y);
+        assert_eq!(picked.shape(), &[2, 3]);
+        assert_eq!(
+            picked.flat().copied().collect::<Vec<i32>>(),
+            vec![10, 2, 10, 20, 2, 20]
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::{array, ops::AddAssign, ops::Range};
+
+use crate::{
+    ndarray::error::{check_axis, check_same_shape, unwrap_shape},
+    Array, ArrayViewMut, ShapeError,
+};
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    /// Gathers the positions in `indices` along `axis`, in their order and
+    /// with repeats, into a new array.
+    ///
+    /// Panics when `axis` or an index is out of bounds, see
+    /// [`Array::try_take`].
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let array = Array::arange(0..6).into_shape([3, 2]);
+    ///
+    /// let rows = array.take(&[2, 0, 2], 0);
+    /// assert_eq!(rows.shape(), &[3, 2]);
+    /// assert_eq!(rows.flat().copied().collect::<Vec<usize>>(), vec![4, 5, 0, 1, 4, 5]);
+    /// ```
+    pub fn take<'b>(&self, indices: &[usize], axis: usize) -> Array<'b, T, D> {
+        unwrap_shape(self.try_take(indices, axis))
+    }
+
+    /// Fallible version of [`Array::take`], failing with
+    /// [`ShapeError::AxisOutOfBounds`] or [`ShapeError::RangeOutOfBounds`].
+    pub fn try_take<'b>(
+        &self,
+        indices: &[usize],
+        axis: usize,
+    ) -> Result<Array<'b, T, D>, ShapeError> {
+        check_axis(axis, D)?;
+        check_indices(indices, axis, self.shape[axis])?;
+
+        let mut shape = self.shape;
+        shape[axis] = indices.len();
+
+        // In standard layout the output is a run of `inner` elements of every
+        // slab in turn, repeated for each position of the axes before `axis`.
+        let outer: usize = shape[..axis].iter().product();
+        let inner: usize = shape[axis + 1..].iter().product();
+
+        let array = self.view();
+        let slabs: Vec<Array<'_, T, D>> = indices
+            .iter()
+            .map(|index| array.slice(&slab(&self.shape, axis, *index)))
+            .collect();
+        let mut slabs: Vec<_> = slabs.iter().map(|slab| slab.flat()).collect();
+
+        let mut vec = Vec::with_capacity(outer * indices.len() * inner);
+        for _ in 0..outer {
+            for slab in slabs.iter_mut() {
+                vec.extend(slab.by_ref().take(inner).cloned());
+            }
+        }
+
+        Array::try_init(vec, shape)
+    }
+
+    /// Writes the slabs of `values` along `axis` to the positions in
+    /// `indices`, the reverse of [`Array::take`]. The last write wins for
+    /// repeated positions.
+    ///
+    /// Panics when `axis` or an index is out of bounds, or when `values` does
+    /// not have the shape of the slabs taken, see [`Array::try_put`].
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let mut array = Array::zeros([2, 3]);
+    /// array.put(&[2, 0], 1, &Array::init(vec![1, 2, 3, 4], [2, 2]));
+    ///
+    /// assert_eq!(array.flat().copied().collect::<Vec<i32>>(), vec![2, 0, 1, 4, 0, 3]);
+    /// ```
+    pub fn put(&mut self, indices: &[usize], axis: usize, values: &Array<'_, T, D>) {
+        self.view_mut().put(indices, axis, values)
+    }
+
+    /// Fallible version of [`Array::put`].
+    pub fn try_put(
+        &mut self,
+        indices: &[usize],
+        axis: usize,
+        values: &Array<'_, T, D>,
+    ) -> Result<(), ShapeError> {
+        self.view_mut().try_put(indices, axis, values)
+    }
+}
+
+impl<'a, T: Clone + AddAssign, const D: usize> Array<'a, T, D> {
+    /// Like [`Array::put`], but adds the values to the positions, so repeated
+    /// positions accumulate every value written to them.
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let mut counts = Array::zeros([3]);
+    /// counts.scatter_add(&[1, 2, 1], 0, &Array::ones([3]));
+    ///
+    /// assert_eq!(counts.flat().copied().collect::<Vec<i32>>(), vec![0, 2, 1]);
+    /// ```
+    pub fn scatter_add(&mut self, indices: &[usize], axis: usize, values: &Array<'_, T, D>) {
+        self.view_mut().scatter_add(indices, axis, values)
+    }
+
+    /// Fallible version of [`Array::scatter_add`].
+    pub fn try_scatter_add(
+        &mut self,
+        indices: &[usize],
+        axis: usize,
+        values: &Array<'_, T, D>,
+    ) -> Result<(), ShapeError> {
+        self.view_mut().try_scatter_add(indices, axis, values)
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Writes into the view, see [`Array::put`].
+    pub fn put(&mut self, indices: &[usize], axis: usize, values: &Array<'_, T, D>) {
+        unwrap_shape(self.try_put(indices, axis, values))
+    }
+
+    /// Fallible version of [`ArrayViewMut::put`].
+    pub fn try_put(
+        &mut self,
+        indices: &[usize],
+        axis: usize,
+        values: &Array<'_, T, D>,
+    ) -> Result<(), ShapeError> {
+        self.scatter_with(indices, axis, values, |elem, val| *elem = val.clone())
+    }
+
+    fn scatter_with<F: FnMut(&mut T, &T)>(
+        &mut self,
+        indices: &[usize],
+        axis: usize,
+        values: &Array<'_, T, D>,
+        mut f: F,
+    ) -> Result<(), ShapeError> {
+        check_axis(axis, D)?;
+        check_indices(indices, axis, self.shape[axis])?;
+
+        let mut shape = self.shape;
+        shape[axis] = indices.len();
+        check_same_shape(&shape, values.shape())?;
+
+        // Slabs are written in the order of `indices`, so later writes to a
+        // repeated position see the earlier ones.
+        for (k, index) in indices.iter().enumerate() {
+            let values = values.slice(&slab(&shape, axis, k));
+
+            self.slice_mut(&slab(&self.shape, axis, *index))
+                .flat_mut()
+                .zip(values.flat())
+                .for_each(|(elem, val)| f(elem, val));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T: Clone + AddAssign, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Accumulates into the view, see [`Array::scatter_add`].
+    pub fn scatter_add(&mut self, indices: &[usize], axis: usize, values: &Array<'_, T, D>) {
+        unwrap_shape(self.try_scatter_add(indices, axis, values))
+    }
+
+    /// Fallible version of [`ArrayViewMut::scatter_add`].
+    pub fn try_scatter_add(
+        &mut self,
+        indices: &[usize],
+        axis: usize,
+        values: &Array<'_, T, D>,
+    ) -> Result<(), ShapeError> {
+        self.scatter_with(indices, axis, values, |elem, val| *elem += val.clone())
+    }
+}
+
+// The ranges selecting position `index` along `axis` of an array of `shape`.
+fn slab<const D: usize>(shape: &[usize; D], axis: usize, index: usize) -> [Range<usize>; D] {
+    array::from_fn(|dim| {
+        if dim == axis {
+            index..index + 1
+        } else {
+            0..shape[dim]
+        }
+    })
+}
+
+fn check_indices(indices: &[usize], axis: usize, len: usize) -> Result<(), ShapeError> {
+    match indices.iter().find(|index| **index >= len) {
+        Some(index) => Err(ShapeError::RangeOutOfBounds {
+            axis,
+            start: *index,
+            end: index + 1,
+            len,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        // 2-D array:
+        // 0 1 2
+        // 3 4 5
+        // 6 7 8
+        let array = Array::arange(0..9).into_shape([3, 3]);
+
+        // rows in a permuted order
+        let permuted = array.take(&[2, 0, 1], 0);
+        assert_eq!(
+            permuted.flat().copied().collect::<Vec<usize>>(),
+            vec![6, 7, 8, 0, 1, 2, 3, 4, 5]
+        );
+
+        // repeated columns
+        let repeated = array.take(&[1, 1], 1);
+        assert_eq!(repeated.shape(), &[3, 2]);
+        assert_eq!(
+            repeated.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 1, 4, 4, 7, 7]
+        );
+
+        assert_eq!(
+            array.try_take(&[0, 3], 0).err(),
+            Some(ShapeError::RangeOutOfBounds {
+                axis: 0,
+                start: 3,
+                end: 4,
+                len: 3
+            })
+        );
+    }
+
+    #[test]
+    fn take_middle_axis() {
+        // 3-D array of two 2x3 matrices, read transposed:
+        // 0 6    1 7    2 8
+        // 3 9    4 10   5 11
+        let array = Array::arange(0..12).into_shape([2, 2, 3]);
+        let transposed = array.t();
+
+        let taken = transposed.take(&[1, 1, 0], 1);
+        assert_eq!(taken.shape(), &[3, 3, 2]);
+        assert_eq!(
+            taken.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 9, 3, 9, 0, 6, 4, 10, 4, 10, 1, 7, 5, 11, 5, 11, 2, 8]
+        );
+    }
+
+    #[test]
+    fn put() {
+        let mut array = Array::zeros([3, 2]);
+
+        let values = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        array.put(&[2, 0], 0, &values);
+
+        // 3 4
+        // 0 0
+        // 1 2
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 4, 0, 0, 1, 2]
+        );
+
+        assert_eq!(
+            array.try_put(&[0], 0, &values).err(),
+            Some(ShapeError::ShapeMismatch {
+                axis: 0,
+                left: 1,
+                right: 2
+            })
+        );
+    }
+
+    #[test]
+    fn scatter_add() {
+        let mut counts = Array::zeros([4]);
+
+        counts.scatter_add(&[1, 3, 1, 1], 0, &Array::ones([4]));
+
+        assert_eq!(
+            counts.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 3, 0, 1]
+        );
+
+        // gradients of an embedding lookup accumulate per row
+        let mut grads = Array::zeros([3, 2]);
+        let updates = Array::init(vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0], [3, 2]);
+        grads
+            .slice_mut(&[0..3, 0..2])
+            .scatter_add(&[0, 2, 0], 0, &updates);
+
+        assert_eq!(
+            grads.flat().copied().collect::<Vec<f64>>(),
+            vec![4.0, 4.0, 0.0, 0.0, 2.0, 2.0]
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use std::borrow::Cow;
-
-use crate::Array;
+use super::{fill_c_strides, IdxMap};
+use crate::{
+    ndarray::error::{check_axis, unwrap_shape},
+    ndarray::storage::Storage,
+    Array, ShapeError,
+};
 
 impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
     pub fn transpose(mut self) -> Array<'a, T, D> {
@@ -12,100 +15,191 @@ impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
     }
 
     pub fn t(&'a self) -> Array<'a, T, D> {
-        let mut shape = self.shape.clone();
-        let mut strides = self.strides.clone();
-        let mut idx_maps = self.idx_maps.clone();
+        let mut shape = self.shape;
+        let mut strides = self.strides;
+        let mut idx_maps = self.idx_maps;
 
         shape.reverse();
         strides.reverse();
         idx_maps.reverse();
 
         Array {
-            vec: Cow::from(&*self.vec),
+            vec: self.vec.view(),
             shape,
             strides,
             idx_maps,
+            offset: self.offset,
         }
     }
 
     pub fn flip(&'a self, axis: usize) -> Array<'a, T, D> {
-        if axis >= D {
-            panic!("Axis out of bounds")
-        }
+        unwrap_shape(self.try_flip(axis))
+    }
 
-        let mut idx_maps = self.idx_maps.clone();
+    pub fn try_flip(&'a self, axis: usize) -> Result<Array<'a, T, D>, ShapeError> {
+        check_axis(axis, D)?;
+
+        let mut idx_maps = self.idx_maps;
 
         let idx_map = &mut idx_maps[axis];
 
-        idx_map.append_b((self.shape[axis] - 1) as isize);
+        idx_map.append_b(self.shape[axis] as isize - 1);
         idx_map.m *= -1;
 
-        Array {
-            vec: Cow::from(&*self.vec),
-            shape: self.shape.clone(),
-            strides: self.strides.clone(),
+        Ok(Array {
+            vec: self.vec.view(),
+            shape: self.shape,
+            strides: self.strides,
             idx_maps,
-        }
+            offset: self.offset,
+        })
     }
 
     pub fn swap_axes(&'a self, axis0: usize, axis1: usize) -> Array<'a, T, D> {
-        if axis0 >= D || axis1 >= D {
-            panic!("Axis out of bounds")
-        }
+        unwrap_shape(self.try_swap_axes(axis0, axis1))
+    }
+
+    pub fn try_swap_axes(
+        &'a self,
+        axis0: usize,
+        axis1: usize,
+    ) -> Result<Array<'a, T, D>, ShapeError> {
+        check_axis(axis0, D)?;
+        check_axis(axis1, D)?;
 
-        let mut shape = self.shape.clone();
-        let mut strides = self.strides.clone();
-        let mut idx_maps = self.idx_maps.clone();
+        let mut shape = self.shape;
+        let mut strides = self.strides;
+        let mut idx_maps = self.idx_maps;
 
         shape.swap(axis0, axis1);
         strides.swap(axis0, axis1);
         idx_maps.swap(axis0, axis1);
 
-        Array {
-            vec: Cow::from(&*self.vec),
+        Ok(Array {
+            vec: self.vec.view(),
             shape,
             strides,
             idx_maps,
-        }
+            offset: self.offset,
+        })
+    }
+
+    /// Whether [`Array::reshape`], [`Array::into_shape`] and [`Array::ravel`]
+    /// can share the buffer of this array. That is the case for a non-empty
+    /// array in standard layout; any other array is copied in logical order.
+    pub fn can_reshape_without_copy(&self) -> bool {
+        !self.is_empty() && self.is_standard_layout()
     }
 
-    pub fn reshape<const S: usize>(&self, shape: [usize; S]) -> Array<'a, T, S> {
-        // TODO: Check wether cloning is necessary
+    /// Returns the elements of the array in logical order with a new shape.
+    ///
+    /// The result is a view sharing the buffer of `self` when
+    /// [`Array::can_reshape_without_copy`] is true, and a copy otherwise.
+    ///
+    /// Panics when `shape` has a different number of elements than `self`.
+    pub fn reshape<const S: usize>(&self, shape: [usize; S]) -> Array<'_, T, S> {
+        unwrap_shape(self.try_reshape(shape))
+    }
 
-        let vec = self.flat().cloned().collect();
+    /// Fallible version of [`Array::reshape`], failing with
+    /// [`ShapeError::ElementCountMismatch`].
+    pub fn try_reshape<const S: usize>(
+        &self,
+        shape: [usize; S],
+    ) -> Result<Array<'_, T, S>, ShapeError> {
+        self.view().try_into_shape(shape)
+    }
 
-        Array::init(vec, shape)
+    /// Like [`Array::reshape`], but consumes the array, so the result keeps
+    /// the buffer of `self` instead of borrowing it.
+    pub fn into_shape<const S: usize>(self, shape: [usize; S]) -> Array<'a, T, S> {
+        unwrap_shape(self.try_into_shape(shape))
     }
 
-    pub fn flatten(&self) -> Array<'a, T, 1> {
-        let vec = self.flat().cloned().collect();
+    /// Fallible version of [`Array::into_shape`], failing with
+    /// [`ShapeError::ElementCountMismatch`].
+    pub fn try_into_shape<const S: usize>(
+        self,
+        shape: [usize; S],
+    ) -> Result<Array<'a, T, S>, ShapeError> {
+        let elem_count: usize = shape.iter().product();
+        let len = self.len();
+
+        if elem_count != len {
+            return Err(ShapeError::ElementCountMismatch {
+                expected: elem_count,
+                found: len,
+            });
+        }
+
+        if !self.can_reshape_without_copy() {
+            let vec = self.flat().cloned().collect();
 
-        Array::init(vec, [self.vec.len()])
+            return Array::try_init(vec, shape);
+        }
+
+        let offset = self.buffer_index(&[0; D]).unwrap();
+
+        let mut strides = [0; S];
+        fill_c_strides(&shape, &mut strides);
+
+        Ok(Array {
+            vec: self.vec,
+            shape,
+            strides,
+            idx_maps: [IdxMap::init(); S],
+            offset,
+        })
     }
 
-    pub fn ravel(&self) -> Array<'a, T, 1> {
-        self.reshape([self.vec.len()])
+    /// Copies the elements of the array in logical order into a new array of
+    /// rank one. Use [`Array::ravel`] to avoid the copy when possible.
+    pub fn flatten<'b>(&self) -> Array<'b, T, 1> {
+        Array::init(self.flat().cloned().collect(), [self.len()])
+    }
+
+    pub fn as_standard_layout(&self) -> Array<'_, T, D> {
+        if self.is_standard_layout() {
+            self.view()
+        } else {
+            Array::init(self.flat().cloned().collect(), self.shape)
+        }
+    }
+
+    /// The elements of the array in logical order as an array of rank one.
+    /// Like [`Array::reshape`], it is a view when
+    /// [`Array::can_reshape_without_copy`] is true and a copy otherwise.
+    pub fn ravel(&self) -> Array<'_, T, 1> {
+        self.reshape([self.len()])
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<T> {
+        let reusable = self.is_standard_layout()
+            && self.buffer_index(&[0; D]) == Some(0)
+            && self.vec.len() == self.len();
+
+        match self.vec {
+            Storage::Owned(vec) if reusable => vec,
+            _ => self.flat().cloned().collect(),
+        }
     }
 }
 
 impl<'a, T: Clone + Default, const D: usize> Array<'a, T, D> {
-    pub fn resize<const S: usize>(mut self, shape: [usize; S]) -> Array<'a, T, S> {
+    pub fn resize<const S: usize>(self, shape: [usize; S]) -> Array<'a, T, S> {
         let new_size = shape.iter().product();
-        let old_size = self.shape().iter().product();
 
-        if new_size > old_size {
-            self.vec.to_mut().resize_with(new_size, || T::default());
-        } else {
-            self.vec.to_mut().truncate(new_size);
-        }
+        let mut vec = self.into_vec();
+        vec.resize_with(new_size, T::default);
 
-        self.reshape(shape)
+        Array::init(vec, shape)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Order;
 
     #[test]
     fn reshape_array() {
@@ -220,4 +314,138 @@ mod tests {
             vec![1, 2, 3, 4, 5, 6]
         )
     }
+
+    #[test]
+    fn try_transformations() {
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        assert_eq!(
+            array.try_flip(2).err(),
+            Some(ShapeError::AxisOutOfBounds { axis: 2, ndim: 2 })
+        );
+        assert_eq!(
+            array.try_swap_axes(0, 3).err(),
+            Some(ShapeError::AxisOutOfBounds { axis: 3, ndim: 2 })
+        );
+        assert_eq!(
+            array.view().try_reshape([4, 2]).err(),
+            Some(ShapeError::ElementCountMismatch {
+                expected: 8,
+                found: 6
+            })
+        );
+        assert!(array.try_reshape([3, 2]).is_ok());
+    }
+
+    #[test]
+    fn reshape_without_copy() {
+        // 2-D array:
+        // 0  1  2
+        // 3  4  5
+        // 6  7  8
+        // 9 10 11
+        let array = Array::arange(0..12).into_shape([4, 3]);
+        assert!(array.is_standard_layout());
+
+        // the middle rows are contiguous so they are reshaped to the 3x2 2-D array:
+        // 3 4
+        // 5 6
+        // 7 8
+        // without copying
+        let rows = array.slice(&[1..3, 0..3]);
+        assert!(rows.is_standard_layout());
+        assert!(rows.can_reshape_without_copy());
+
+        let reshaped = rows.reshape([3, 2]);
+
+        assert!(reshaped.shares_buffer_with(&array));
+        assert_eq!(reshaped[[0, 0]], 3);
+        assert_eq!(reshaped[[2, 1]], 8);
+    }
+
+    #[test]
+    fn reshape_borrows_into_shape_consumes() {
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        // the array is still usable after reshaping it
+        let reshaped = array.reshape([3, 2]);
+        assert!(reshaped.shares_buffer_with(&array));
+        assert_eq!(array[[1, 0]], 3);
+        assert_eq!(reshaped[[1, 0]], 2);
+
+        // consuming the array keeps its buffer
+        let ptr = array.vec.as_ptr();
+        let reshaped = array.into_shape([6]);
+        assert_eq!(reshaped.vec.as_ptr(), ptr);
+
+        // empty arrays are never views
+        let empty: Array<i32, 2> = Array::init(vec![], [0, 3]);
+        assert!(!empty.can_reshape_without_copy());
+        assert_eq!(empty.reshape([3, 0]).shape(), &[3, 0]);
+    }
+
+    #[test]
+    fn reshape_with_copy() {
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        // columns of the array are not contiguous
+        let columns = array.slice(&[0..2, 1..3]);
+        assert!(!columns.is_standard_layout());
+        assert!(!columns.can_reshape_without_copy());
+
+        let reshaped = columns.reshape([4]);
+
+        assert!(!reshaped.shares_buffer_with(&array));
+        assert_eq!(
+            reshaped.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 4, 5]
+        );
+    }
+
+    #[test]
+    fn ravel_and_flatten() {
+        let array = Array::arange(0..6).into_shape([2, 3]);
+
+        let ravelled = array.ravel();
+        assert!(ravelled.shares_buffer_with(&array));
+
+        let flattened_t = array.t().flatten();
+        assert!(!flattened_t.shares_buffer_with(&array));
+        assert_eq!(
+            flattened_t.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 3, 1, 4, 2, 5]
+        );
+    }
+
+    #[test]
+    fn resize() {
+        let array = Array::arange(1..5).into_shape([2, 2]);
+
+        let grown = array.resize([2, 3]);
+        assert_eq!(
+            grown.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 0, 0]
+        );
+
+        let shrunk = grown.resize([3]);
+        assert_eq!(
+            shrunk.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn as_standard_layout() {
+        // 2-D array stored in column major order:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init_with_order(vec![1, 4, 2, 5, 3, 6], [2, 3], Order::F);
+
+        let standard = array.as_standard_layout();
+
+        assert!(standard.is_c_contiguous());
+        assert!(!standard.shares_buffer_with(&array));
+        assert_eq!(standard[[1, 0]], 4);
+        assert!(standard.as_standard_layout().shares_buffer_with(&standard));
+    }
 }
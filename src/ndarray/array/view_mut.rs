@@ -0,0 +1,177 @@
+use std::ops::{Index, IndexMut};
+
+use super::{offset_of, slice_layout, IdxMap};
+use crate::{
+    ndarray::error::{check_same_shape, unwrap_shape},
+    ndarray::storage::Storage,
+    Array, AxisSlice, ShapeError,
+};
+
+pub struct ArrayViewMut<'a, T: Clone, const D: usize> {
+    pub(crate) vec: &'a mut [T],
+    pub(crate) shape: [usize; D],
+    pub(crate) strides: [usize; D],
+    pub(crate) idx_maps: [IdxMap; D],
+    pub(crate) offset: usize,
+}
+
+impl<'a, T: Clone, const D: usize> ArrayViewMut<'a, T, D> {
+    pub fn shape(&self) -> &[usize; D] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[usize; D] {
+        &self.strides
+    }
+
+    pub fn view(&self) -> Array<'_, T, D> {
+        Array {
+            vec: Storage::from(&*self.vec),
+            shape: self.shape,
+            strides: self.strides,
+            idx_maps: self.idx_maps,
+            offset: self.offset,
+        }
+    }
+
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, D> {
+        ArrayViewMut {
+            vec: &mut *self.vec,
+            shape: self.shape,
+            strides: self.strides,
+            idx_maps: self.idx_maps,
+            offset: self.offset,
+        }
+    }
+
+    pub fn slice_mut<S: Clone + Into<AxisSlice>>(
+        &mut self,
+        slice: &[S; D],
+    ) -> ArrayViewMut<'_, T, D> {
+        unwrap_shape(self.try_slice_mut(slice))
+    }
+
+    pub fn try_slice_mut<S: Clone + Into<AxisSlice>>(
+        &mut self,
+        slice: &[S; D],
+    ) -> Result<ArrayViewMut<'_, T, D>, ShapeError> {
+        let mut shape = self.shape;
+        let mut idx_maps = self.idx_maps;
+        slice_layout(&mut shape, &mut idx_maps, slice)?;
+
+        Ok(ArrayViewMut {
+            vec: &mut *self.vec,
+            shape,
+            strides: self.strides,
+            idx_maps,
+            offset: self.offset,
+        })
+    }
+
+    pub(crate) fn buffer_index(&self, indices: &[usize; D]) -> Option<usize> {
+        offset_of(
+            self.offset,
+            &self.shape,
+            &self.strides,
+            &self.idx_maps,
+            indices,
+        )
+    }
+
+    pub fn get(&self, indices: [usize; D]) -> Option<&T> {
+        self.buffer_index(&indices)
+            .and_then(|index| self.vec.get(index))
+    }
+
+    pub fn get_mut(&mut self, indices: [usize; D]) -> Option<&mut T> {
+        self.buffer_index(&indices)
+            .and_then(move |index| self.vec.get_mut(index))
+    }
+
+    pub fn fill(&mut self, val: T) {
+        self.map_inplace(|elem| *elem = val.clone());
+    }
+
+    pub fn assign(&mut self, array: &Array<'_, T, D>) {
+        unwrap_shape(self.try_assign(array))
+    }
+
+    pub fn try_assign(&mut self, array: &Array<'_, T, D>) -> Result<(), ShapeError> {
+        check_same_shape(&self.shape, array.shape())?;
+
+        self.flat_mut()
+            .zip(array.flat())
+            .for_each(|(elem, val)| *elem = val.clone());
+
+        Ok(())
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Index<[usize; D]> for ArrayViewMut<'a, T, D> {
+    type Output = T;
+
+    fn index(&self, indices: [usize; D]) -> &Self::Output {
+        match self.get(indices) {
+            Some(value) => value,
+            None => panic!("Index out of bound"),
+        }
+    }
+}
+
+impl<'a, T: Clone, const D: usize> IndexMut<[usize; D]> for ArrayViewMut<'a, T, D> {
+    fn index_mut(&mut self, indices: [usize; D]) -> &mut Self::Output {
+        match self.get_mut(indices) {
+            Some(value) => value,
+            None => panic!("Index out of bound"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill() {
+        let mut array = Array::zeros([2, 2]);
+
+        array.view_mut().fill(7);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![7, 7, 7, 7]
+        );
+    }
+
+    #[test]
+    fn assign() {
+        // 2-D array:
+        // 0 0 0
+        // 0 0 0
+        let mut array = Array::zeros([2, 3]);
+
+        // write a column vector into the last column:
+        // 0 0 1
+        // 0 0 2
+        let column = Array::init(vec![1, 2], [2, 1]);
+        array.slice_mut(&[0..2, 2..3]).assign(&column);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 0, 1, 0, 0, 2]
+        );
+    }
+
+    #[test]
+    fn view_of_view_mut() {
+        let mut array = Array::arange(0..4).into_shape([2, 2]);
+
+        let mut view = array.slice_mut(&[0..2, 1..2]);
+        view[[0, 0]] = 10;
+
+        assert_eq!(
+            view.view().flat().copied().collect::<Vec<usize>>(),
+            vec![10, 3]
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use std::array;
+
+use super::iter::Indices;
+use crate::{ndarray::error::unwrap_shape, Array, ShapeError};
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    /// Every overlapping view of `shape` over the array, one element apart
+    /// along each axis, in logical order. Views are not copied.
+    ///
+    /// Panics when an axis of `shape` is zero, see [`Array::try_windows`].
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let array = Array::arange(0..9).into_shape([3, 3]);
+    ///
+    /// let sums: Vec<usize> = array.windows([2, 2]).map(|window| window.sum()).collect();
+    /// assert_eq!(sums, vec![8, 12, 20, 24]);
+    /// ```
+    pub fn windows(&self, shape: [usize; D]) -> Windows<'_, T, D> {
+        Windows::init(self, shape, [1; D])
+    }
+
+    /// Fallible version of [`Array::windows`], failing with
+    /// [`ShapeError::ZeroLength`] for a zero axis of `shape`.
+    pub fn try_windows(&self, shape: [usize; D]) -> Result<Windows<'_, T, D>, ShapeError> {
+        Windows::try_init(self, shape, [1; D])
+    }
+
+    /// Like [`Array::windows`], but the starts of the views are `stride`
+    /// apart along each axis.
+    ///
+    /// Panics when an axis of `shape` or `stride` is zero, see
+    /// [`Array::try_windows_with_stride`].
+    pub fn windows_with_stride(&self, shape: [usize; D], stride: [usize; D]) -> Windows<'_, T, D> {
+        Windows::init(self, shape, stride)
+    }
+
+    /// Fallible version of [`Array::windows_with_stride`], failing with
+    /// [`ShapeError::ZeroLength`] for a zero axis of `shape` and with
+    /// [`ShapeError::ZeroStep`] for a zero axis of `stride`.
+    pub fn try_windows_with_stride(
+        &self,
+        shape: [usize; D],
+        stride: [usize; D],
+    ) -> Result<Windows<'_, T, D>, ShapeError> {
+        Windows::try_init(self, shape, stride)
+    }
+
+    /// Non-overlapping views of `shape` tiling the array, in logical order.
+    /// The partial blocks at the end of an axis that is not a multiple of the
+    /// chunk length are left out.
+    ///
+    /// Panics when an axis of `shape` is zero, see [`Array::try_exact_chunks`].
+    pub fn exact_chunks(&self, shape: [usize; D]) -> Chunks<'_, T, D> {
+        Chunks::init(self, shape, true)
+    }
+
+    /// Fallible version of [`Array::exact_chunks`], failing with
+    /// [`ShapeError::ZeroLength`] for a zero axis of `shape`.
+    pub fn try_exact_chunks(&self, shape: [usize; D]) -> Result<Chunks<'_, T, D>, ShapeError> {
+        Chunks::try_init(self, shape, true)
+    }
+
+    /// Like [`Array::exact_chunks`], but the partial blocks at the end of each
+    /// axis are yielded too, with a smaller shape.
+    ///
+    /// ```
+    /// use nd_array::Array;
+    ///
+    /// let array = Array::arange(0..5);
+    ///
+    /// let lens: Vec<usize> = array.chunks([2]).map(|chunk| chunk.len()).collect();
+    /// assert_eq!(lens, vec![2, 2, 1]);
+    /// ```
+    pub fn chunks(&self, shape: [usize; D]) -> Chunks<'_, T, D> {
+        Chunks::init(self, shape, false)
+    }
+
+    /// Fallible version of [`Array::chunks`], failing with
+    /// [`ShapeError::ZeroLength`] for a zero axis of `shape`.
+    pub fn try_chunks(&self, shape: [usize; D]) -> Result<Chunks<'_, T, D>, ShapeError> {
+        Chunks::try_init(self, shape, false)
+    }
+}
+
+/// Iterator over overlapping views of a fixed shape, whose starts are
+/// `stride` apart along each axis. Windows that would run past the end of an
+/// axis are not yielded. Created by [`Array::windows`] and
+/// [`Array::windows_with_stride`].
+pub struct Windows<'a, T: Clone, const D: usize> {
+    array: &'a Array<'a, T, D>,
+    shape: [usize; D],
+    stride: [usize; D],
+    starts: Indices<D>,
+    remaining: usize,
+}
+
+impl<'a, T: Clone, const D: usize> Windows<'a, T, D> {
+    pub fn init(
+        array: &'a Array<'a, T, D>,
+        shape: [usize; D],
+        stride: [usize; D],
+    ) -> Windows<'a, T, D> {
+        unwrap_shape(Windows::try_init(array, shape, stride))
+    }
+
+    pub fn try_init(
+        array: &'a Array<'a, T, D>,
+        shape: [usize; D],
+        stride: [usize; D],
+    ) -> Result<Windows<'a, T, D>, ShapeError> {
+        if let Some(axis) = shape.iter().position(|len| *len == 0) {
+            return Err(ShapeError::ZeroLength { axis });
+        }
+        if let Some(axis) = stride.iter().position(|step| *step == 0) {
+            return Err(ShapeError::ZeroStep { axis });
+        }
+
+        let mut counts = [0; D];
+        for axis in 0..D {
+            if array.shape[axis] >= shape[axis] {
+                counts[axis] = (array.shape[axis] - shape[axis]) / stride[axis] + 1;
+            }
+        }
+
+        Ok(Windows {
+            array,
+            shape,
+            stride,
+            starts: Indices::init(counts),
+            remaining: counts.iter().product(),
+        })
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Iterator for Windows<'a, T, D> {
+    type Item = Array<'a, T, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let starts = self.starts.advance();
+
+        let slice: [_; D] = array::from_fn(|axis| {
+            let start = starts[axis] * self.stride[axis];
+            start..start + self.shape[axis]
+        });
+
+        Some(self.array.slice(&slice))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ExactSizeIterator for Windows<'a, T, D> {}
+
+/// Iterator over non-overlapping blocks tiling an array. Exact chunks leave
+/// out the partial blocks at the end of each axis, other chunks yield them
+/// with a smaller shape. Created by [`Array::chunks`] and
+/// [`Array::exact_chunks`].
+pub struct Chunks<'a, T: Clone, const D: usize> {
+    array: &'a Array<'a, T, D>,
+    shape: [usize; D],
+    starts: Indices<D>,
+    remaining: usize,
+}
+
+impl<'a, T: Clone, const D: usize> Chunks<'a, T, D> {
+    pub fn init(array: &'a Array<'a, T, D>, shape: [usize; D], exact: bool) -> Chunks<'a, T, D> {
+        unwrap_shape(Chunks::try_init(array, shape, exact))
+    }
+
+    pub fn try_init(
+        array: &'a Array<'a, T, D>,
+        shape: [usize; D],
+        exact: bool,
+    ) -> Result<Chunks<'a, T, D>, ShapeError> {
+        if let Some(axis) = shape.iter().position(|len| *len == 0) {
+            return Err(ShapeError::ZeroLength { axis });
+        }
+
+        let counts: [usize; D] = array::from_fn(|axis| {
+            if exact {
+                array.shape[axis] / shape[axis]
+            } else {
+                array.shape[axis].div_ceil(shape[axis])
+            }
+        });
+
+        Ok(Chunks {
+            array,
+            shape,
+            starts: Indices::init(counts),
+            remaining: counts.iter().product(),
+        })
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Iterator for Chunks<'a, T, D> {
+    type Item = Array<'a, T, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let starts = self.starts.advance();
+
+        let slice: [_; D] = array::from_fn(|axis| {
+            let start = starts[axis] * self.shape[axis];
+            start..(start + self.shape[axis]).min(self.array.shape[axis])
+        });
+
+        Some(self.array.slice(&slice))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone, const D: usize> ExactSizeIterator for Chunks<'a, T, D> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn windows() {
+        // 2-D array:
+        // 0 1 2
+        // 3 4 5
+        // 6 7 8
+        let array = Array::arange(0..9).into_shape([3, 3]);
+
+        let sums: Vec<usize> = array.windows([2, 2]).map(|window| window.sum()).collect();
+        assert_eq!(sums, vec![8, 12, 20, 24]);
+
+        assert_eq!(array.windows([3, 1]).len(), 3);
+        assert_eq!(array.windows([4, 1]).count(), 0);
+    }
+
+    #[test]
+    fn windows_with_stride() {
+        let array = Array::arange(0..20).into_shape([4, 5]);
+
+        let corners: Vec<usize> = array
+            .windows_with_stride([2, 2], [2, 3])
+            .map(|window| window[[0, 0]])
+            .collect();
+        assert_eq!(corners, vec![0, 3, 10, 13]);
+
+        // windows of a reversed view follow the view
+        let view = array.slice(&s![.., ..;-1]);
+        let first = view.windows([1, 2]).next().unwrap();
+        assert_eq!(first.flat().copied().collect::<Vec<usize>>(), vec![4, 3]);
+
+        assert!(matches!(
+            array.try_windows_with_stride([1, 1], [1, 0]),
+            Err(ShapeError::ZeroStep { axis: 1 })
+        ));
+        assert!(matches!(
+            array.try_windows([0, 2]),
+            Err(ShapeError::ZeroLength { axis: 0 })
+        ));
+    }
+
+    #[test]
+    fn chunks() {
+        // 2-D array:
+        // 0  1  2  3  4
+        // 5  6  7  8  9
+        // 10 11 12 13 14
+        let array = Array::arange(0..15).into_shape([3, 5]);
+
+        let exact: Vec<[usize; 2]> = array
+            .exact_chunks([2, 2])
+            .map(|chunk| *chunk.shape())
+            .collect();
+        assert_eq!(exact, vec![[2, 2], [2, 2]]);
+
+        let shapes: Vec<[usize; 2]> = array.chunks([2, 2]).map(|chunk| *chunk.shape()).collect();
+        assert_eq!(shapes, vec![[2, 2], [2, 2], [2, 1], [1, 2], [1, 2], [1, 1]]);
+
+        let last = array.chunks([2, 2]).last().unwrap();
+        assert_eq!(last.flat().copied().collect::<Vec<usize>>(), vec![14]);
+
+        // mini-batches of rows
+        let batches: Vec<usize> = array.chunks([2, 5]).map(|batch| batch.sum()).collect();
+        assert_eq!(batches, vec![45, 60]);
+
+        assert!(matches!(
+            array.try_chunks([0, 2]),
+            Err(ShapeError::ZeroLength { axis: 0 })
+        ));
+    }
+}
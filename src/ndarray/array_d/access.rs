@@ -0,0 +1,166 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    ndarray::{
+        array::{is_broadcast, offset_of, slice_layout},
+        error::{check_rank, unwrap_shape},
+    },
+    ArrayD, AxisSlice, ShapeError,
+};
+
+impl<'a, T: Clone> ArrayD<'a, T> {
+    pub fn slice<S: Clone + Into<AxisSlice>>(&self, slice: &[S]) -> ArrayD<'_, T> {
+        unwrap_shape(self.try_slice(slice))
+    }
+
+    pub fn try_slice<S: Clone + Into<AxisSlice>>(
+        &self,
+        slice: &[S],
+    ) -> Result<ArrayD<'_, T>, ShapeError> {
+        check_rank(slice.len(), self.ndim())?;
+
+        let mut shape = self.shape.clone();
+        let mut idx_maps = self.idx_maps.clone();
+        slice_layout(&mut shape, &mut idx_maps, slice)?;
+
+        Ok(ArrayD {
+            vec: self.vec.view(),
+            shape,
+            strides: self.strides.clone(),
+            idx_maps,
+            offset: self.offset,
+        })
+    }
+
+    pub fn view(&self) -> ArrayD<'_, T> {
+        ArrayD {
+            vec: self.vec.view(),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            idx_maps: self.idx_maps.clone(),
+            offset: self.offset,
+        }
+    }
+
+    pub(crate) fn buffer_index(&self, indices: &[usize]) -> Option<usize> {
+        offset_of(
+            self.offset,
+            &self.shape,
+            &self.strides,
+            &self.idx_maps,
+            indices,
+        )
+    }
+
+    pub fn get(&self, indices: &[usize]) -> Option<&T> {
+        self.buffer_index(indices)
+            .and_then(|index| self.vec.get(index))
+    }
+
+    pub fn get_mut(&mut self, indices: &[usize]) -> Option<&mut T> {
+        self.unbroadcast();
+
+        let index = self.buffer_index(indices)?;
+
+        self.vec.to_mut().get_mut(index)
+    }
+
+    fn unbroadcast(&mut self) {
+        if is_broadcast(&self.shape, &self.strides) {
+            *self = ArrayD::init(self.flat().cloned().collect(), &self.shape);
+        }
+    }
+}
+
+impl<'a, 'i, T: Clone> Index<&'i [usize]> for ArrayD<'a, T> {
+    type Output = T;
+
+    fn index(&self, indices: &'i [usize]) -> &Self::Output {
+        match self.get(indices) {
+            Some(value) => value,
+            None => panic!("Index out of bound"),
+        }
+    }
+}
+
+impl<'a, 'i, T: Clone> IndexMut<&'i [usize]> for ArrayD<'a, T> {
+    fn index_mut(&mut self, indices: &'i [usize]) -> &mut Self::Output {
+        match self.get_mut(indices) {
+            Some(value) => value,
+            None => panic!("Index out of bound"),
+        }
+    }
+}
+
+impl<'a, T: Clone, const N: usize> Index<[usize; N]> for ArrayD<'a, T> {
+    type Output = T;
+
+    fn index(&self, indices: [usize; N]) -> &Self::Output {
+        &self[&indices[..]]
+    }
+}
+
+impl<'a, T: Clone, const N: usize> IndexMut<[usize; N]> for ArrayD<'a, T> {
+    fn index_mut(&mut self, indices: [usize; N]) -> &mut Self::Output {
+        &mut self[&indices[..]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn index_array() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let mut array = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+
+        assert_eq!(array[[0, 2]], 3);
+        assert_eq!(array[&[1, 1][..]], 5);
+        assert_eq!(array.get(&[2, 0]), None);
+        assert_eq!(array.get(&[0]), None);
+
+        array[[1, 2]] = 60;
+        assert_eq!(array[[1, 2]], 60);
+    }
+
+    #[test]
+    fn slicing() {
+        // 2-D array:
+        // 1   2  3  4
+        // 5   6  7  8
+        // 9  10 11 12
+        let array = ArrayD::init((1..13).collect(), &[3, 4]);
+
+        // 6  7
+        // 10 11
+        let slice = array.slice(&[1..3, 1..3]);
+
+        assert_eq!(
+            slice.flat().copied().collect::<Vec<usize>>(),
+            vec![6, 7, 10, 11]
+        );
+        assert_eq!(
+            array.try_slice(&[0..1, 1..3, 0..1]).err(),
+            Some(ShapeError::RankMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn stepped_slicing() {
+        let array = ArrayD::init((0..12).collect(), &[3, 4]);
+
+        let slice = array.slice(&s![..;2, 1..;-2]);
+
+        assert_eq!(
+            slice.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 1, 11, 9]
+        );
+    }
+}
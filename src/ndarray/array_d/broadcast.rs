@@ -0,0 +1,72 @@
+use crate::{
+    ndarray::{
+        array::{broadcast_layout, IdxMap},
+        error::unwrap_shape,
+    },
+    ArrayD, ShapeError,
+};
+
+impl<'a, T: Clone> ArrayD<'a, T> {
+    pub fn broadcast(&self, shape: &[usize]) -> ArrayD<'_, T> {
+        unwrap_shape(self.try_broadcast(shape))
+    }
+
+    pub fn try_broadcast(&self, shape: &[usize]) -> Result<ArrayD<'_, T>, ShapeError> {
+        let mut strides = vec![0; shape.len()];
+        let mut idx_maps = vec![IdxMap::init(); shape.len()];
+        let offset = broadcast_layout(
+            self.offset,
+            &self.shape,
+            &self.strides,
+            &self.idx_maps,
+            shape,
+            &mut strides,
+            &mut idx_maps,
+        )?;
+
+        Ok(ArrayD {
+            vec: self.vec.view(),
+            shape: shape.to_vec(),
+            strides,
+            idx_maps,
+            offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn broadcast() {
+        let row = ArrayD::arange(0..3);
+
+        let cube = row.broadcast(&[2, 2, 3]);
+        assert_eq!(cube.shape(), &[2, 2, 3]);
+        assert_eq!(cube[[1, 1, 2]], 2);
+
+        assert_eq!(
+            row.try_broadcast(&[3, 2]).map(|_| ()),
+            Err(ShapeError::IncompatibleShapes {
+                left: vec![3],
+                right: vec![3, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn write_to_broadcast() {
+        let row = ArrayD::arange(0..3);
+        let mut matrix = row.broadcast(&[2, 3]);
+
+        // the broadcast axis gets its own elements before the first write
+        matrix[[0, 1]] = 9;
+
+        assert_eq!(
+            matrix.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 9, 2, 0, 1, 2]
+        );
+        assert_eq!(row.flat().copied().collect::<Vec<usize>>(), vec![0, 1, 2]);
+    }
+}
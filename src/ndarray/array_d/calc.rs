@@ -0,0 +1,187 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use num_traits::{FromPrimitive, One, Zero};
+
+use crate::{
+    ndarray::array::{mean_of, positions_of, prod_of, sum_of, var_of},
+    ArrayD,
+};
+
+impl<'a, T: Clone + Ord> ArrayD<'a, T> {
+    pub fn max(&self) -> Option<T> {
+        self.flat().max().cloned()
+    }
+
+    pub fn arg_max(&self) -> Vec<usize> {
+        self.max()
+            .map_or_else(Vec::new, |max| positions_of(self.flat(), &max))
+    }
+
+    pub fn max_across(&self, axis: usize) -> Vec<Option<T>> {
+        self.axis_view(axis).map(|view| view.max()).collect()
+    }
+
+    pub fn arg_max_across(&self, axis: usize) -> Vec<Option<usize>> {
+        self.axis_view(axis)
+            .map(|view| view.arg_max().first().copied())
+            .collect()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.flat().min().cloned()
+    }
+
+    pub fn arg_min(&self) -> Vec<usize> {
+        self.min()
+            .map_or_else(Vec::new, |min| positions_of(self.flat(), &min))
+    }
+
+    pub fn min_across(&self, axis: usize) -> Vec<Option<T>> {
+        self.axis_view(axis).map(|view| view.min()).collect()
+    }
+
+    pub fn arg_min_across(&self, axis: usize) -> Vec<Option<usize>> {
+        self.axis_view(axis)
+            .map(|view| view.arg_min().first().copied())
+            .collect()
+    }
+
+    pub fn clip(&self, min: &T, max: &T) -> ArrayD<'a, T> {
+        self.map(|val| val.clamp(min, max).clone())
+    }
+}
+
+impl<'a, T> ArrayD<'a, T>
+where
+    T: Clone + Ord + Sub<Output = T>,
+{
+    pub fn ptp(&self) -> Option<T> {
+        self.max().and_then(|max| self.min().map(|min| max - min))
+    }
+
+    pub fn ptp_across(&self, axis: usize) -> Vec<Option<T>> {
+        self.axis_view(axis).map(|view| view.ptp()).collect()
+    }
+}
+
+impl<'a, T> ArrayD<'a, T>
+where
+    T: Clone + Add<Output = T> + Zero,
+{
+    pub fn sum(&self) -> T {
+        sum_of(self.flat())
+    }
+
+    pub fn sum_across(&self, axis: usize) -> Vec<T> {
+        self.axis_view(axis).map(|view| view.sum()).collect()
+    }
+}
+
+impl<'a, T> ArrayD<'a, T>
+where
+    T: Clone + Mul<Output = T> + One,
+{
+    pub fn prod(&self) -> T {
+        prod_of(self.flat())
+    }
+
+    pub fn prod_across(&self, axis: usize) -> Vec<T> {
+        self.axis_view(axis).map(|view| view.prod()).collect()
+    }
+}
+
+impl<'a, T> ArrayD<'a, T>
+where
+    T: Clone + Add<Output = T> + FromPrimitive + Div<T, Output = T> + Zero,
+{
+    pub fn mean(&self) -> T {
+        mean_of(self.flat(), self.len())
+    }
+
+    pub fn mean_across(&self, axis: usize) -> Vec<T> {
+        self.axis_view(axis).map(|view| view.mean()).collect()
+    }
+}
+
+impl<'a, T> ArrayD<'a, T>
+where
+    T: Clone + Sub<Output = T> + FromPrimitive + Div<T, Output = T> + Mul<Output = T> + Zero,
+{
+    pub fn var(&self) -> T {
+        var_of(self.flat(), self.mean(), self.len())
+    }
+
+    pub fn var_across(&self, axis: usize) -> Vec<T> {
+        self.axis_view(axis).map(|view| view.var()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_min() {
+        // 0 1
+        // 2 3
+        let array = ArrayD::init(vec![0, 1, 2, 3], &[2, 2]);
+
+        assert_eq!(array.max(), Some(3));
+        assert_eq!(array.min(), Some(0));
+        assert_eq!(array.max_across(0), vec![Some(1), Some(3)]);
+        assert_eq!(array.min_across(1), vec![Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn arg_max_min() {
+        // 3 1 3
+        // 0 2 0
+        let array = ArrayD::init(vec![3, 1, 3, 0, 2, 0], &[2, 3]);
+
+        assert_eq!(array.arg_max(), vec![0, 2]);
+        assert_eq!(array.arg_min(), vec![3, 5]);
+        assert_eq!(array.arg_max_across(0), vec![Some(0), Some(1)]);
+        assert_eq!(array.arg_min_across(1), vec![Some(1), Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn ptp_clip() {
+        // 3 1 3
+        // 0 2 0
+        let array = ArrayD::init(vec![3, 1, 3, 0, 2, 0], &[2, 3]);
+
+        assert_eq!(array.ptp(), Some(3));
+        assert_eq!(array.ptp_across(0), vec![Some(2), Some(2)]);
+
+        let clipped = array.slice(&[0..2, 1..3]).clip(&1, &2);
+        assert_eq!(clipped.shape(), &[2, 2]);
+        assert_eq!(
+            clipped.flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 2, 1]
+        );
+    }
+
+    #[test]
+    fn sum_prod() {
+        // 1 2
+        // 3 4
+        let array = ArrayD::init(vec![1, 2, 3, 4], &[2, 2]);
+
+        assert_eq!(array.sum(), 10);
+        assert_eq!(array.sum_across(0), vec![3, 7]);
+        assert_eq!(array.prod(), 24);
+        assert_eq!(array.prod_across(1), vec![3, 8]);
+    }
+
+    #[test]
+    fn mean_var() {
+        // 1 2
+        // 3 4
+        let array = ArrayD::init(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]);
+
+        assert_eq!(array.mean(), 2.5);
+        assert_eq!(array.mean_across(1), vec![2.0, 3.0]);
+        assert_eq!(array.var(), 1.25);
+        assert_eq!(array.var_across(0), vec![0.25, 0.25]);
+    }
+}
@@ -0,0 +1,139 @@
+use std::{iter::FusedIterator, ops::Range};
+
+use crate::{
+    ndarray::{
+        array::{IdxMap, IterInner, Walk},
+        error::{check_axis, unwrap_shape},
+    },
+    ArrayD,
+};
+
+impl<'a, T: Clone> ArrayD<'a, T> {
+    pub fn flat(&self) -> IterD<'_, T> {
+        IterD::init(self)
+    }
+
+    pub fn axis_view(&self, axis: usize) -> AxisViewD<'_, T> {
+        AxisViewD::init(self, axis)
+    }
+}
+
+pub struct IterD<'a, T: Clone> {
+    inner: IterInner<'a, T, Vec<usize>, Vec<IdxMap>>,
+}
+
+impl<'a, T: Clone> IterD<'a, T> {
+    fn init(array: &'a ArrayD<'_, T>) -> Self {
+        let walk = Walk::init(
+            array.offset,
+            array.shape.clone(),
+            array.strides.clone(),
+            array.idx_maps.clone(),
+        );
+
+        IterD {
+            inner: IterInner::init(&array.vec, walk),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for IterD<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for IterD<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IterD<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IterD<'a, T> {}
+
+pub struct AxisViewD<'a, T: Clone> {
+    array: &'a ArrayD<'a, T>,
+    slice: Vec<Range<usize>>,
+    axis: usize,
+    idx: usize,
+}
+
+impl<'a, T: Clone> AxisViewD<'a, T> {
+    pub fn init(array: &'a ArrayD<'a, T>, axis: usize) -> AxisViewD<'a, T> {
+        unwrap_shape(check_axis(axis, array.ndim()));
+
+        AxisViewD {
+            array,
+            slice: array.shape().iter().map(|len| 0..*len).collect(),
+            axis,
+            idx: 0,
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for AxisViewD<'a, T> {
+    type Item = ArrayD<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx < self.array.shape[self.axis] {
+            self.slice[self.axis] = self.idx..self.idx + 1;
+            let view = self.array.slice(&self.slice);
+
+            self.idx += 1;
+
+            Some(view)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn iter() {
+        let array = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 1, 3]);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(array.axis_view(2).count(), 3);
+    }
+
+    #[test]
+    fn iter_strided() {
+        // 2-D array:
+        // 0 1 2
+        // 3 4 5
+        let array = ArrayD::init((0..6).collect(), &[2, 3]);
+
+        // 2 0
+        // 5 3
+        let view = array.slice(&s![.., ..;-2]);
+        let iter = view.flat();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(
+            iter.rev().copied().collect::<Vec<usize>>(),
+            vec![3, 5, 0, 2]
+        );
+
+        let column = ArrayD::arange(0..2);
+        let broadcast = column.broadcast(&[2, 2]);
+        assert_eq!(
+            broadcast.t().flat().copied().collect::<Vec<usize>>(),
+            vec![0, 0, 1, 1]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    ndarray::{
+        array::{broadcast_shape, covers_buffer},
+        error::unwrap_shape,
+        storage::Storage,
+    },
+    ArrayD, ShapeError,
+};
+
+impl<'a, T: Clone> ArrayD<'a, T> {
+    /// Applies `f` to every element into a new array of the same shape, the
+    /// way [`Array::map`] does.
+    ///
+    /// [`Array::map`]: crate::Array::map
+    pub fn map<'b, U: Clone, F: FnMut(&T) -> U>(&self, f: F) -> ArrayD<'b, U> {
+        if covers_buffer(&self.shape, &self.strides, self.vec.len()) {
+            ArrayD {
+                vec: Storage::from(self.vec.iter().map(f).collect::<Vec<U>>()),
+                shape: self.shape.clone(),
+                strides: self.strides.clone(),
+                idx_maps: self.idx_maps.clone(),
+                offset: self.offset,
+            }
+        } else {
+            ArrayD::init(self.flat().map(f).collect(), &self.shape)
+        }
+    }
+
+    /// Like [`ArrayD::map`], but `f` takes the elements by value.
+    pub fn mapv<'b, U: Clone, F: FnMut(T) -> U>(&self, mut f: F) -> ArrayD<'b, U>
+    where
+        T: Copy,
+    {
+        self.map(|val| f(*val))
+    }
+
+    /// Like [`ArrayD::map`], but consumes the array and moves the elements out
+    /// of an owned buffer instead of cloning them.
+    pub fn map_into<'b, U: Clone, F: FnMut(T) -> U>(self, f: F) -> ArrayD<'b, U> {
+        if covers_buffer(&self.shape, &self.strides, self.vec.len()) {
+            let vec: Vec<U> = match self.vec {
+                Storage::Owned(vec) => vec.into_iter().map(f).collect(),
+                vec => vec.iter().cloned().map(f).collect(),
+            };
+
+            ArrayD {
+                vec: Storage::from(vec),
+                shape: self.shape,
+                strides: self.strides,
+                idx_maps: self.idx_maps,
+                offset: self.offset,
+            }
+        } else {
+            ArrayD::init(self.flat().cloned().map(f).collect(), &self.shape)
+        }
+    }
+
+    /// Combines the elements of both arrays after broadcasting them to a common
+    /// shape, which has the rank of the larger of the two.
+    ///
+    /// Panics when the shapes can not be broadcast, see
+    /// [`ArrayD::try_zip_with`].
+    pub fn zip_with<'b, U: Clone, V: Clone, F>(&self, rhs: &ArrayD<'_, U>, f: F) -> ArrayD<'b, V>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        unwrap_shape(self.try_zip_with(rhs, f))
+    }
+
+    /// Fallible version of [`ArrayD::zip_with`], failing with
+    /// [`ShapeError::IncompatibleShapes`].
+    pub fn try_zip_with<'b, U: Clone, V: Clone, F>(
+        &self,
+        rhs: &ArrayD<'_, U>,
+        mut f: F,
+    ) -> Result<ArrayD<'b, V>, ShapeError>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        let shape = broadcast_shape(&self.shape, &rhs.shape)?;
+
+        let lhs = self.try_broadcast(&shape)?;
+        let rhs = rhs.try_broadcast(&shape)?;
+
+        let vec = lhs
+            .flat()
+            .zip(rhs.flat())
+            .map(|(lhs, rhs)| f(lhs, rhs))
+            .collect();
+
+        ArrayD::try_init(vec, &shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map() {
+        // 0 1 2
+        // 3 4 5
+        let array = ArrayD::init((0..6).collect(), &[2, 3]);
+
+        let labels = array.map(|val| format!("#{}", val));
+        assert_eq!(labels.shape(), &[2, 3]);
+        assert_eq!(labels[[1, 2]], "#5");
+
+        // a view only maps its own elements:
+        // 4 5
+        let halves = array.slice(&[1..2, 1..3]).mapv(|val| val as f64 / 2.0);
+        assert_eq!(halves.flat().copied().collect::<Vec<f64>>(), vec![2.0, 2.5]);
+
+        let words = ArrayD::init(vec![String::from("a"), String::from("bb")], &[2]);
+        let lengths = words.map_into(|word| word.len());
+        assert_eq!(lengths.flat().copied().collect::<Vec<usize>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn zip_with() {
+        // a row combined with every row of a matrix:
+        // 3 4    1 2
+        // 6 8
+        let matrix = ArrayD::init(vec![3, 4, 6, 8], &[2, 2]);
+        let row = ArrayD::init(vec![1, 2], &[2]);
+
+        let scaled = row.zip_with(&matrix, |scale, val| scale * val);
+        assert_eq!(scaled.shape(), &[2, 2]);
+        assert_eq!(
+            scaled.flat().copied().collect::<Vec<i32>>(),
+            vec![3, 8, 6, 16]
+        );
+        assert_eq!(
+            matrix
+                .try_zip_with(&ArrayD::init(vec![1, 2, 3], &[3]), |a, b| a + b)
+                .err(),
+            Some(ShapeError::IncompatibleShapes {
+                left: vec![2, 2],
+                right: vec![3]
+            })
+        );
+    }
+}
@@ -0,0 +1,253 @@
+mod access;
+mod broadcast;
+mod calc;
+mod iter;
+mod map;
+mod ops;
+mod transformation;
+
+use std::convert::TryFrom;
+
+use num_traits::{One, Zero};
+
+use crate::{
+    ndarray::storage::Storage,
+    ndarray::{
+        array::{fill_c_strides, is_standard_layout, IdxMap},
+        error::{check_rank, unwrap_shape},
+    },
+    Array, ShapeError,
+};
+
+pub struct ArrayD<'a, T: Clone> {
+    vec: Storage<'a, T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    idx_maps: Vec<IdxMap>,
+    offset: usize,
+}
+
+impl<'a, T: Clone> ArrayD<'a, T> {
+    pub fn init(vec: Vec<T>, shape: &[usize]) -> Self {
+        unwrap_shape(ArrayD::try_init(vec, shape))
+    }
+
+    pub fn try_init(vec: Vec<T>, shape: &[usize]) -> Result<Self, ShapeError> {
+        let elem_count: usize = shape.iter().product();
+
+        if elem_count != vec.len() {
+            return Err(ShapeError::ElementCountMismatch {
+                expected: elem_count,
+                found: vec.len(),
+            });
+        }
+
+        let mut strides = vec![0; shape.len()];
+        fill_c_strides(shape, &mut strides);
+
+        Ok(ArrayD {
+            vec: Storage::from(vec),
+            shape: shape.to_vec(),
+            strides,
+            idx_maps: vec![IdxMap::init(); shape.len()],
+            offset: 0,
+        })
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_standard_layout(&self) -> bool {
+        is_standard_layout(&self.shape, &self.strides, &self.idx_maps)
+    }
+
+    pub fn full(val: T, shape: &[usize]) -> ArrayD<'a, T> {
+        ArrayD::init(vec![val; shape.iter().product()], shape)
+    }
+
+    pub fn full_like<'b, U: Clone>(val: T, array: &ArrayD<'b, U>) -> ArrayD<'a, T> {
+        ArrayD::full(val, array.shape())
+    }
+
+    pub fn arange<I: Iterator<Item = T>>(range: I) -> ArrayD<'a, T> {
+        let vec: Vec<T> = range.collect();
+        let len = vec.len();
+
+        ArrayD::init(vec, &[len])
+    }
+
+    pub fn into_shared<'b>(self) -> ArrayD<'b, T> {
+        ArrayD {
+            vec: self.vec.into_shared(),
+            shape: self.shape,
+            strides: self.strides,
+            idx_maps: self.idx_maps,
+            offset: self.offset,
+        }
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.vec.is_shared()
+    }
+
+    pub fn into_dimensionality<const D: usize>(self) -> Result<Array<'a, T, D>, ShapeError> {
+        check_rank(self.ndim(), D)?;
+
+        let mut shape = [0; D];
+        let mut strides = [0; D];
+        let mut idx_maps = [IdxMap::init(); D];
+
+        shape.copy_from_slice(&self.shape);
+        strides.copy_from_slice(&self.strides);
+        idx_maps.copy_from_slice(&self.idx_maps);
+
+        Ok(Array {
+            vec: self.vec,
+            shape,
+            strides,
+            idx_maps,
+            offset: self.offset,
+        })
+    }
+}
+
+impl<'a, T: Clone> Clone for ArrayD<'a, T> {
+    fn clone(&self) -> Self {
+        ArrayD {
+            vec: self.vec.clone(),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            idx_maps: self.idx_maps.clone(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<'a, T: Clone + Zero> ArrayD<'a, T> {
+    pub fn zeros(shape: &[usize]) -> Self {
+        ArrayD::init(vec![T::zero(); shape.iter().product()], shape)
+    }
+
+    pub fn zeros_like<'b, U: Clone>(array: &ArrayD<'b, U>) -> ArrayD<'a, T> {
+        ArrayD::zeros(array.shape())
+    }
+}
+
+impl<'a, T: Clone + One> ArrayD<'a, T> {
+    pub fn ones(shape: &[usize]) -> Self {
+        ArrayD::init(vec![T::one(); shape.iter().product()], shape)
+    }
+
+    pub fn ones_like<'b, U: Clone>(array: &ArrayD<'b, U>) -> ArrayD<'a, T> {
+        ArrayD::ones(array.shape())
+    }
+}
+
+impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+    pub fn into_dyn(self) -> ArrayD<'a, T> {
+        ArrayD {
+            vec: self.vec,
+            shape: self.shape.to_vec(),
+            strides: self.strides.to_vec(),
+            idx_maps: self.idx_maps.to_vec(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<'a, T: Clone, const D: usize> From<Array<'a, T, D>> for ArrayD<'a, T> {
+    fn from(array: Array<'a, T, D>) -> Self {
+        array.into_dyn()
+    }
+}
+
+impl<'a, T: Clone, const D: usize> TryFrom<ArrayD<'a, T>> for Array<'a, T, D> {
+    type Error = ShapeError;
+
+    fn try_from(array: ArrayD<'a, T>) -> Result<Self, Self::Error> {
+        array.into_dimensionality()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init() {
+        // rank is only known at runtime
+        let shape = vec![2, 3, 2];
+        let array = ArrayD::init((0..12).collect(), &shape);
+
+        assert_eq!(array.ndim(), 3);
+        assert_eq!(array.shape(), &[2, 3, 2]);
+        assert_eq!(array.strides(), &[6, 2, 1]);
+        assert_eq!(
+            ArrayD::try_init(vec![1, 2, 3], &[2, 2]).err(),
+            Some(ShapeError::ElementCountMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn creation() {
+        let zeros = ArrayD::zeros(&[2, 2]);
+        let ones = ArrayD::ones_like(&zeros);
+        let full = ArrayD::full(7, &[3]);
+
+        assert_eq!(zeros.flat().copied().collect::<Vec<i32>>(), vec![0; 4]);
+        assert_eq!(ones.flat().copied().collect::<Vec<i32>>(), vec![1; 4]);
+        assert_eq!(full.flat().copied().collect::<Vec<i32>>(), vec![7; 3]);
+    }
+
+    #[test]
+    fn conversions() {
+        let array = Array::arange(0..6).into_shape([2, 3]).transpose();
+
+        let dyn_array = array.into_dyn();
+        assert_eq!(dyn_array.shape(), &[3, 2]);
+
+        assert_eq!(
+            dyn_array.view().into_dimensionality::<3>().err(),
+            Some(ShapeError::RankMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let array: Array<usize, 2> = Array::try_from(dyn_array).unwrap();
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 3, 1, 4, 2, 5]
+        );
+    }
+
+    #[test]
+    fn shared() {
+        let array = ArrayD::init(vec![1, 2, 3, 4], &[2, 2]).into_shared();
+
+        let transposed = array.t().into_shared();
+
+        assert!(transposed.is_shared());
+        assert_eq!(transposed.vec.as_ptr(), array.vec.as_ptr());
+    }
+}
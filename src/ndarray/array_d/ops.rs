@@ -0,0 +1,165 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::{ndarray::error::unwrap_shape, ArrayD, ScalarOperand, ShapeError};
+
+impl<'a, T: Clone + Neg<Output = T>> Neg for ArrayD<'a, T> {
+    type Output = ArrayD<'a, T>;
+
+    fn neg(self) -> Self::Output {
+        self.map_into(|val| -val)
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $checked:ident, $op:tt) => {
+        impl<'a, T: Clone + $trait<Output = T>> ArrayD<'a, T> {
+            pub fn $checked<'b>(&self, rhs: &ArrayD<'b, T>) -> Result<ArrayD<'a, T>, ShapeError> {
+                self.try_zip_with(rhs, |v1, v2| v1.clone() $op v2.clone())
+            }
+        }
+
+        impl<'a, 'b, T: Clone + $trait<Output = T>> $trait<&ArrayD<'b, T>> for &ArrayD<'a, T> {
+            type Output = ArrayD<'a, T>;
+
+            fn $method(self, rhs: &ArrayD<'b, T>) -> Self::Output {
+                unwrap_shape(self.$checked(rhs))
+            }
+        }
+
+        forward_binary_op!(ArrayD [] [], $trait, $method, $op);
+    };
+}
+
+impl_binary_op!(Add, add, checked_add, +);
+impl_binary_op!(Sub, sub, checked_sub, -);
+impl_binary_op!(Mul, mul, checked_mul, *);
+impl_binary_op!(Div, div, checked_div, /);
+impl_binary_op!(Rem, rem, checked_rem, %);
+
+impl_scalar_op!(ArrayD [], Add, add, +);
+impl_scalar_op!(ArrayD [], Sub, sub, -);
+impl_scalar_op!(ArrayD [], Mul, mul, *);
+impl_scalar_op!(ArrayD [], Div, div, /);
+impl_scalar_op!(ArrayD [], Rem, rem, %);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn neg() {
+        let array = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+
+        assert_eq!(
+            (-array).flat().copied().collect::<Vec<i32>>(),
+            vec![-1, -2, -3, -4, -5, -6]
+        );
+
+        // the element outside of the view is never negated
+        let array = ArrayD::init(vec![i32::MIN, 2, 4], &[3]);
+        assert_eq!(
+            (-array.slice(&s![1..3]))
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![-2, -4]
+        );
+    }
+
+    #[test]
+    fn add_sub() {
+        let array = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+        let array2 = ArrayD::init(vec![6, 5, 4, 3, 2, 1], &[2, 3]);
+
+        assert_eq!(
+            (&array + &array2).flat().copied().collect::<Vec<i32>>(),
+            vec![7, 7, 7, 7, 7, 7]
+        );
+        assert_eq!(
+            (array - array2).flat().copied().collect::<Vec<i32>>(),
+            vec![-5, -3, -1, 1, 3, 5]
+        );
+    }
+
+    #[test]
+    fn checked_add() {
+        let array = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+        let array2 = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[6]);
+
+        assert_eq!(
+            array.checked_add(&array2).err(),
+            Some(ShapeError::IncompatibleShapes {
+                left: vec![2, 3],
+                right: vec![6]
+            })
+        );
+    }
+
+    #[test]
+    fn broadcast_add() {
+        // a matrix of shape [2, 3] added to every matrix of a [2, 1, 3] cube
+        let matrix = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+        let cube = ArrayD::init(vec![10, 20, 30, 40, 50, 60], &[2, 1, 3]);
+
+        let sum = &matrix + &cube;
+        assert_eq!(sum.shape(), &[2, 2, 3]);
+        assert_eq!(
+            sum.flat().copied().collect::<Vec<i32>>(),
+            vec![11, 22, 33, 14, 25, 36, 41, 52, 63, 44, 55, 66]
+        );
+    }
+
+    #[test]
+    fn mul_div() {
+        let array = ArrayD::init(vec![2, 4, 6, 8], &[2, 2]);
+
+        assert_eq!(
+            (&array + 1).flat().copied().collect::<Vec<i32>>(),
+            vec![3, 5, 7, 9]
+        );
+
+        assert_eq!(
+            (&array * 2).flat().copied().collect::<Vec<i32>>(),
+            vec![4, 8, 12, 16]
+        );
+        assert_eq!(
+            (array / 2).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn mul_div_rem() {
+        let array = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+        let array2 = ArrayD::init(vec![2, 2, 2, 3, 3, 3], &[2, 3]);
+
+        assert_eq!(
+            (&array * &array2).flat().copied().collect::<Vec<i32>>(),
+            vec![2, 4, 6, 12, 15, 18]
+        );
+        assert_eq!(
+            (&array / &array2).flat().copied().collect::<Vec<i32>>(),
+            vec![0, 1, 1, 1, 1, 2]
+        );
+        assert_eq!(
+            (array % array2).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 0, 1, 1, 2, 0]
+        );
+    }
+
+    #[test]
+    fn scalar_ops_on_views() {
+        let array = ArrayD::init(vec![i32::MIN, 2, 4, 6], &[4]);
+
+        // the element outside of the view is never divided
+        let view = array.slice(&s![1..4]);
+        let quotient = view / -1;
+
+        assert!(quotient.is_standard_layout());
+        assert_eq!(
+            quotient.flat().copied().collect::<Vec<i32>>(),
+            vec![-2, -4, -6]
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    ndarray::{
+        array::{fill_c_strides, IdxMap},
+        error::unwrap_shape,
+    },
+    ArrayD, ShapeError,
+};
+
+impl<'a, T: Clone> ArrayD<'a, T> {
+    pub fn transpose(mut self) -> ArrayD<'a, T> {
+        self.shape.reverse();
+        self.strides.reverse();
+        self.idx_maps.reverse();
+
+        self
+    }
+
+    pub fn t(&self) -> ArrayD<'_, T> {
+        let mut shape = self.shape.clone();
+        let mut strides = self.strides.clone();
+        let mut idx_maps = self.idx_maps.clone();
+
+        shape.reverse();
+        strides.reverse();
+        idx_maps.reverse();
+
+        ArrayD {
+            vec: self.vec.view(),
+            shape,
+            strides,
+            idx_maps,
+            offset: self.offset,
+        }
+    }
+
+    /// Whether [`ArrayD::reshape`] and [`ArrayD::into_shape`] can share the
+    /// buffer of this array, as for [`Array::can_reshape_without_copy`].
+    ///
+    /// [`Array::can_reshape_without_copy`]: crate::Array::can_reshape_without_copy
+    pub fn can_reshape_without_copy(&self) -> bool {
+        !self.is_empty() && self.is_standard_layout()
+    }
+
+    /// Returns the elements of the array in logical order with a new shape:
+    /// a view sharing the buffer of `self` when
+    /// [`ArrayD::can_reshape_without_copy`] is true, and a copy otherwise.
+    ///
+    /// Panics when `shape` has a different number of elements than `self`.
+    pub fn reshape(&self, shape: &[usize]) -> ArrayD<'_, T> {
+        unwrap_shape(self.try_reshape(shape))
+    }
+
+    /// Fallible version of [`ArrayD::reshape`], failing with
+    /// [`ShapeError::ElementCountMismatch`].
+    pub fn try_reshape(&self, shape: &[usize]) -> Result<ArrayD<'_, T>, ShapeError> {
+        self.view().try_into_shape(shape)
+    }
+
+    /// Like [`ArrayD::reshape`], but consumes the array, so the result keeps
+    /// the buffer of `self` instead of borrowing it.
+    pub fn into_shape(self, shape: &[usize]) -> ArrayD<'a, T> {
+        unwrap_shape(self.try_into_shape(shape))
+    }
+
+    /// Fallible version of [`ArrayD::into_shape`], failing with
+    /// [`ShapeError::ElementCountMismatch`].
+    pub fn try_into_shape(self, shape: &[usize]) -> Result<ArrayD<'a, T>, ShapeError> {
+        let elem_count: usize = shape.iter().product();
+        let len = self.len();
+
+        if elem_count != len {
+            return Err(ShapeError::ElementCountMismatch {
+                expected: elem_count,
+                found: len,
+            });
+        }
+
+        if !self.can_reshape_without_copy() {
+            let vec = self.flat().cloned().collect();
+
+            return ArrayD::try_init(vec, shape);
+        }
+
+        let offset = self.buffer_index(&vec![0; self.ndim()]).unwrap();
+
+        let mut strides = vec![0; shape.len()];
+        fill_c_strides(shape, &mut strides);
+
+        Ok(ArrayD {
+            vec: self.vec,
+            shape: shape.to_vec(),
+            strides,
+            idx_maps: vec![IdxMap::init(); shape.len()],
+            offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transpose() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = ArrayD::init(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+
+        // 1 4
+        // 2 5
+        // 3 6
+        let transposed = array.t();
+
+        assert_eq!(transposed.shape(), &[3, 2]);
+        assert_eq!(transposed[[0, 1]], 4);
+        assert_eq!(transposed[[2, 0]], 3);
+        assert_eq!(
+            array.transpose().flat().copied().collect::<Vec<usize>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+    }
+
+    #[test]
+    fn reshape() {
+        let array = ArrayD::arange(0..6);
+
+        let reshaped = array.reshape(&[3, 1, 2]);
+
+        assert_eq!(reshaped.ndim(), 3);
+        assert_eq!(reshaped[[2, 0, 1]], 5);
+        assert_eq!(reshaped.vec.as_ptr(), array.vec.as_ptr());
+        assert!(reshaped.try_reshape(&[4, 2]).is_err());
+
+        // consuming the array keeps its buffer even when it is owned
+        let ptr = array.vec.as_ptr();
+        let reshaped = array.into_shape(&[2, 3]);
+        assert_eq!(reshaped.vec.as_ptr(), ptr);
+        assert!(!reshaped.t().can_reshape_without_copy());
+    }
+}
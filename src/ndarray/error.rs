@@ -0,0 +1,132 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    ShapeMismatch {
+        axis: usize,
+        left: usize,
+        right: usize,
+    },
+    AxisOutOfBounds {
+        axis: usize,
+        ndim: usize,
+    },
+    RangeOutOfBounds {
+        axis: usize,
+        start: usize,
+        end: usize,
+        len: usize,
+    },
+    ElementCountMismatch {
+        expected: usize,
+        found: usize,
+    },
+    RankMismatch {
+        expected: usize,
+        found: usize,
+    },
+    ZeroStep {
+        axis: usize,
+    },
+    ZeroLength {
+        axis: usize,
+    },
+    IncompatibleShapes {
+        left: Vec<usize>,
+        right: Vec<usize>,
+    },
+    BroadcastRankMismatch {
+        rank: usize,
+        shape: Vec<usize>,
+    },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::ShapeMismatch { axis, left, right } => write!(
+                f,
+                "Shape of array at axis: {} is not equal to right hand side: {} != {}",
+                axis, left, right
+            ),
+            ShapeError::AxisOutOfBounds { axis, ndim } => {
+                write!(f, "Axis out of bounds: {} >= {}", axis, ndim)
+            }
+            ShapeError::RangeOutOfBounds {
+                axis,
+                start,
+                end,
+                len,
+            } => write!(
+                f,
+                "Range: [{},{}) is out of bounds for axis: {} with length: {}",
+                start, end, axis, len
+            ),
+            ShapeError::ElementCountMismatch { expected, found } => write!(
+                f,
+                "Number of elements in vec is not equal to dimension specification: {} != {}",
+                found, expected
+            ),
+            ShapeError::RankMismatch { expected, found } => write!(
+                f,
+                "Number of dimensions is not equal to expected rank: {} != {}",
+                found, expected
+            ),
+            ShapeError::ZeroStep { axis } => write!(f, "Step of slice for axis: {} is zero", axis),
+            ShapeError::ZeroLength { axis } => write!(f, "Length for axis: {} is zero", axis),
+            ShapeError::IncompatibleShapes { left, right } => write!(
+                f,
+                "Shapes: {:?} and {:?} can not be broadcast together",
+                left, right
+            ),
+            ShapeError::BroadcastRankMismatch { rank, shape } => write!(
+                f,
+                "Broadcast shape: {:?} has more axes than the left hand side of rank: {}",
+                shape, rank
+            ),
+        }
+    }
+}
+
+impl Error for ShapeError {}
+
+pub(crate) fn check_axis(axis: usize, ndim: usize) -> Result<(), ShapeError> {
+    if axis >= ndim {
+        Err(ShapeError::AxisOutOfBounds { axis, ndim })
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn check_rank(ndim: usize, expected: usize) -> Result<(), ShapeError> {
+    if ndim != expected {
+        Err(ShapeError::RankMismatch {
+            expected,
+            found: ndim,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn check_same_shape(left: &[usize], right: &[usize]) -> Result<(), ShapeError> {
+    check_rank(right.len(), left.len())?;
+
+    match left
+        .iter()
+        .zip(right)
+        .enumerate()
+        .find(|(_, (left, right))| left != right)
+    {
+        Some((axis, (left, right))) => Err(ShapeError::ShapeMismatch {
+            axis,
+            left: *left,
+            right: *right,
+        }),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn unwrap_shape<T>(result: Result<T, ShapeError>) -> T {
+    result.unwrap_or_else(|err| panic!("{}", err))
+}
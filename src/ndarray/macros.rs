@@ -0,0 +1,65 @@
+// Operator impls shared by `Array` and `ArrayD`. The array type is given with
+// its rank parameter in brackets, `Array [D]`, or with empty brackets for a
+// type without one, `ArrayD []`.
+
+// Applies a scalar operand to every element of an array, by reference or by
+// value.
+macro_rules! impl_scalar_op {
+    ($array:ident [$($dim:ident)?], $trait:ident, $method:ident, $op:tt) => {
+        impl<'a, U: ScalarOperand, O: 'a + Clone, T: Clone + $trait<U, Output = O> $(, const $dim: usize)?>
+            $trait<U> for &$array<'a, T $(, $dim)?>
+        {
+            type Output = $array<'a, O $(, $dim)?>;
+
+            fn $method(self, rhs: U) -> Self::Output {
+                self.map(|val| val.clone() $op rhs.clone())
+            }
+        }
+
+        impl<'a, U: ScalarOperand, O: 'a + Clone, T: Clone + $trait<U, Output = O> $(, const $dim: usize)?>
+            $trait<U> for $array<'a, T $(, $dim)?>
+        {
+            type Output = $array<'a, O $(, $dim)?>;
+
+            fn $method(self, rhs: U) -> Self::Output {
+                &self $op rhs
+            }
+        }
+    };
+}
+
+// The value and reference combinations of a binary operator between arrays,
+// forwarding to the impl for two references.
+macro_rules! forward_binary_op {
+    ($array:ident [$($ldim:ident)?] [$($rdim:ident)?], $trait:ident, $method:ident, $op:tt) => {
+        impl<'a, 'b, T: Clone + $trait<Output = T> $(, const $ldim: usize)? $(, const $rdim: usize)?>
+            $trait<$array<'b, T $(, $rdim)?>> for &$array<'a, T $(, $ldim)?>
+        {
+            type Output = $array<'a, T $(, $ldim)?>;
+
+            fn $method(self, rhs: $array<'b, T $(, $rdim)?>) -> Self::Output {
+                self $op &rhs
+            }
+        }
+
+        impl<'a, 'b, T: Clone + $trait<Output = T> $(, const $ldim: usize)? $(, const $rdim: usize)?>
+            $trait<&$array<'b, T $(, $rdim)?>> for $array<'a, T $(, $ldim)?>
+        {
+            type Output = $array<'a, T $(, $ldim)?>;
+
+            fn $method(self, rhs: &$array<'b, T $(, $rdim)?>) -> Self::Output {
+                &self $op rhs
+            }
+        }
+
+        impl<'a, 'b, T: Clone + $trait<Output = T> $(, const $ldim: usize)? $(, const $rdim: usize)?>
+            $trait<$array<'b, T $(, $rdim)?>> for $array<'a, T $(, $ldim)?>
+        {
+            type Output = $array<'a, T $(, $ldim)?>;
+
+            fn $method(self, rhs: $array<'b, T $(, $rdim)?>) -> Self::Output {
+                &self $op &rhs
+            }
+        }
+    };
+}
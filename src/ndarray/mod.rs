@@ -1,3 +1,19 @@
+#[macro_use]
+mod macros;
+
 mod array;
+mod array_d;
+mod error;
+mod order;
+mod scalar;
+mod slice;
+mod storage;
+mod zip;
 
-pub use array::Array;
+pub use array::{Array, ArrayViewMut};
+pub use array_d::ArrayD;
+pub use error::ShapeError;
+pub use order::{IterOrder, Order};
+pub use scalar::{IntoOperand, ScalarOperand};
+pub use slice::AxisSlice;
+pub use zip::{NdProducer, Zip};
@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    C,
+    F,
+}
+
+// Memory order visits the elements in the order they are stored in the buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IterOrder {
+    C,
+    F,
+    Memory,
+}
+
+impl From<Order> for IterOrder {
+    fn from(order: Order) -> Self {
+        match order {
+            Order::C => IterOrder::C,
+            Order::F => IterOrder::F,
+        }
+    }
+}
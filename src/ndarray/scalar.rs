@@ -0,0 +1,61 @@
+use crate::Array;
+
+/// Types that binary operators treat as a single value applied to every
+/// element, rather than as another array.
+///
+/// The primitive numeric types and `bool` implement it. Before this trait,
+/// `Mul` and `Div` accepted any `Clone` right hand side. That blanket impl
+/// would overlap with the array-by-array impls, so other scalar types now opt
+/// in with an empty impl:
+///
+/// ```
+/// use nd_array::{Array, ScalarOperand};
+/// use std::ops::Mul;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Meters(f64);
+///
+/// impl ScalarOperand for Meters {}
+///
+/// impl Mul<Meters> for f64 {
+///     type Output = Meters;
+///
+///     fn mul(self, rhs: Meters) -> Meters {
+///         Meters(self * rhs.0)
+///     }
+/// }
+///
+/// let scales = Array::init(vec![1.0, 2.0], [2]);
+/// let lengths = &scales * Meters(1.5);
+///
+/// assert_eq!(lengths[[1]], Meters(3.0));
+/// ```
+pub trait ScalarOperand: Clone {}
+
+macro_rules! impl_scalar_operand {
+    ($($ty:ty),*) => {
+        $(impl ScalarOperand for $ty {})*
+    };
+}
+
+impl_scalar_operand!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool
+);
+
+// Right hand sides of element-wise functions: an array, or a scalar that acts
+// as an array of rank zero and is broadcast to any shape.
+pub trait IntoOperand<'a, T: Clone, const R: usize> {
+    fn into_operand(self) -> Array<'a, T, R>;
+}
+
+impl<'a, T: ScalarOperand> IntoOperand<'a, T, 0> for T {
+    fn into_operand(self) -> Array<'a, T, 0> {
+        Array::init(vec![self], [])
+    }
+}
+
+impl<'a, 'b, T: Clone, const R: usize> IntoOperand<'a, T, R> for &'a Array<'b, T, R> {
+    fn into_operand(self) -> Array<'a, T, R> {
+        self.view()
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisSlice {
+    pub start: usize,
+    pub end: Option<usize>,
+    pub step: isize,
+}
+
+impl AxisSlice {
+    pub fn new(start: usize, end: Option<usize>, step: isize) -> Self {
+        AxisSlice { start, end, step }
+    }
+
+    // Negative steps walk the range backwards starting from its last element.
+    pub fn step(self, step: isize) -> Self {
+        AxisSlice { step, ..self }
+    }
+}
+
+impl From<Range<usize>> for AxisSlice {
+    fn from(range: Range<usize>) -> Self {
+        AxisSlice::new(range.start, Some(range.end), 1)
+    }
+}
+
+impl From<RangeInclusive<usize>> for AxisSlice {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        AxisSlice::new(*range.start(), Some(*range.end() + 1), 1)
+    }
+}
+
+impl From<RangeFrom<usize>> for AxisSlice {
+    fn from(range: RangeFrom<usize>) -> Self {
+        AxisSlice::new(range.start, None, 1)
+    }
+}
+
+impl From<RangeTo<usize>> for AxisSlice {
+    fn from(range: RangeTo<usize>) -> Self {
+        AxisSlice::new(0, Some(range.end), 1)
+    }
+}
+
+impl From<RangeToInclusive<usize>> for AxisSlice {
+    fn from(range: RangeToInclusive<usize>) -> Self {
+        AxisSlice::new(0, Some(range.end + 1), 1)
+    }
+}
+
+impl From<RangeFull> for AxisSlice {
+    fn from(_: RangeFull) -> Self {
+        AxisSlice::new(0, None, 1)
+    }
+}
+
+// A single index keeps the axis with a length of one.
+impl From<usize> for AxisSlice {
+    fn from(index: usize) -> Self {
+        AxisSlice::new(index, Some(index + 1), 1)
+    }
+}
+
+#[macro_export]
+macro_rules! s {
+    ($($range:expr $(; $step:expr)?),* $(,)?) => {
+        [$($crate::AxisSlice::from($range)$(.step($step))?),*]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_macro() {
+        let slice = s![1..3, 2.., ..4;2, ..;-1, 5];
+
+        assert_eq!(
+            slice,
+            [
+                AxisSlice::new(1, Some(3), 1),
+                AxisSlice::new(2, None, 1),
+                AxisSlice::new(0, Some(4), 2),
+                AxisSlice::new(0, None, -1),
+                AxisSlice::new(5, Some(6), 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::{ops::Deref, sync::Arc};
+
+pub(crate) enum Storage<'a, T> {
+    Owned(Vec<T>),
+    Borrowed(&'a [T]),
+    Shared(Arc<Vec<T>>),
+}
+
+impl<'a, T: Clone> Storage<'a, T> {
+    // Shared buffers are only copied when another array still refers to them.
+    pub(crate) fn to_mut(&mut self) -> &mut Vec<T> {
+        if let Storage::Borrowed(slice) = *self {
+            *self = Storage::Owned(slice.to_vec());
+        }
+
+        match self {
+            Storage::Owned(vec) => vec,
+            Storage::Shared(vec) => Arc::make_mut(vec),
+            Storage::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    pub(crate) fn view(&self) -> Storage<'_, T> {
+        match self {
+            Storage::Shared(vec) => Storage::Shared(Arc::clone(vec)),
+            _ => Storage::Borrowed(self),
+        }
+    }
+
+    pub(crate) fn into_shared<'b>(self) -> Storage<'b, T> {
+        match self {
+            Storage::Owned(vec) => Storage::Shared(Arc::new(vec)),
+            Storage::Borrowed(slice) => Storage::Shared(Arc::new(slice.to_vec())),
+            Storage::Shared(vec) => Storage::Shared(vec),
+        }
+    }
+
+    pub(crate) fn is_shared(&self) -> bool {
+        matches!(self, Storage::Shared(_))
+    }
+}
+
+impl<'a, T> Deref for Storage<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Storage::Owned(vec) => vec,
+            Storage::Borrowed(slice) => slice,
+            Storage::Shared(vec) => vec,
+        }
+    }
+}
+
+impl<'a, T: Clone> Clone for Storage<'a, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Storage::Owned(vec) => Storage::Owned(vec.clone()),
+            Storage::Borrowed(slice) => Storage::Borrowed(slice),
+            Storage::Shared(vec) => Storage::Shared(Arc::clone(vec)),
+        }
+    }
+}
+
+impl<'a, T> From<Vec<T>> for Storage<'a, T> {
+    fn from(vec: Vec<T>) -> Self {
+        Storage::Owned(vec)
+    }
+}
+
+impl<'a, T> From<&'a [T]> for Storage<'a, T> {
+    fn from(slice: &'a [T]) -> Self {
+        Storage::Borrowed(slice)
+    }
+}
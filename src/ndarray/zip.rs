@@ -0,0 +1,253 @@
+use crate::{
+    ndarray::array::{Iter, IterMut},
+    ndarray::error::{check_same_shape, unwrap_shape},
+    Array, ArrayViewMut, ShapeError,
+};
+
+// Anything that can take part in a `Zip`: a shape and a flat iterator over its
+// elements in logical order.
+pub trait NdProducer<const D: usize> {
+    type Item;
+    type Iter: Iterator<Item = Self::Item>;
+
+    fn shape(&self) -> [usize; D];
+
+    fn into_flat(self) -> Self::Iter;
+}
+
+impl<'a, 'b, T: Clone, const D: usize> NdProducer<D> for &'a Array<'b, T, D> {
+    type Item = &'a T;
+    type Iter = Iter<'a, T, D>;
+
+    fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    fn into_flat(self) -> Self::Iter {
+        self.flat()
+    }
+}
+
+impl<'a, 'b, T: Clone, const D: usize> NdProducer<D> for &'a mut Array<'b, T, D> {
+    type Item = &'a mut T;
+    type Iter = IterMut<'a, T, D>;
+
+    fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    fn into_flat(self) -> Self::Iter {
+        self.flat_mut()
+    }
+}
+
+impl<'a, T: Clone, const D: usize> NdProducer<D> for ArrayViewMut<'a, T, D> {
+    type Item = &'a mut T;
+    type Iter = IterMut<'a, T, D>;
+
+    fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    fn into_flat(self) -> Self::Iter {
+        IterMut::init(self)
+    }
+}
+
+impl<'a, 'b, T: Clone, const D: usize> NdProducer<D> for &'a mut ArrayViewMut<'b, T, D> {
+    type Item = &'a mut T;
+    type Iter = IterMut<'a, T, D>;
+
+    fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    fn into_flat(self) -> Self::Iter {
+        self.flat_mut()
+    }
+}
+
+// Walks up to six producers of the same shape in lockstep. The shapes are
+// checked once when a producer joins, so the walk itself never checks them.
+pub struct Zip<P, const D: usize> {
+    parts: P,
+    shape: [usize; D],
+}
+
+impl<P: NdProducer<D>, const D: usize> Zip<(P,), D> {
+    pub fn init(part: P) -> Self {
+        Zip {
+            shape: part.shape(),
+            parts: (part,),
+        }
+    }
+}
+
+impl<P, const D: usize> Zip<P, D> {
+    pub fn shape(&self) -> &[usize; D] {
+        &self.shape
+    }
+}
+
+macro_rules! zip_and {
+    ($($part:ident),+; $new:ident) => {
+        #[allow(non_snake_case)]
+        impl<$($part: NdProducer<D>,)+ const D: usize> Zip<($($part,)+), D> {
+            pub fn and<$new: NdProducer<D>>(self, part: $new) -> Zip<($($part,)+ $new,), D> {
+                unwrap_shape(self.try_and(part))
+            }
+
+            pub fn try_and<$new: NdProducer<D>>(
+                self,
+                part: $new,
+            ) -> Result<Zip<($($part,)+ $new,), D>, ShapeError> {
+                check_same_shape(&self.shape, &part.shape())?;
+
+                let ($($part,)+) = self.parts;
+
+                Ok(Zip {
+                    parts: ($($part,)+ part,),
+                    shape: self.shape,
+                })
+            }
+        }
+    };
+}
+
+zip_and!(P1; P2);
+zip_and!(P1, P2; P3);
+zip_and!(P1, P2, P3; P4);
+zip_and!(P1, P2, P3, P4; P5);
+zip_and!(P1, P2, P3, P4, P5; P6);
+
+macro_rules! zip_apply {
+    ($($part:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($part: NdProducer<D>,)+ const D: usize> Zip<($($part,)+), D> {
+            pub fn for_each<F: FnMut($($part::Item),+)>(self, mut f: F) {
+                let ($($part,)+) = self.parts;
+                let ($(mut $part,)+) = ($($part.into_flat(),)+);
+
+                // all parts have the same shape, so they run out together
+                while let ($(Some($part),)+) = ($($part.next(),)+) {
+                    f($($part),+);
+                }
+            }
+
+            pub fn map_collect<'a, R: Clone, F: FnMut($($part::Item),+) -> R>(
+                self,
+                mut f: F,
+            ) -> Array<'a, R, D> {
+                let shape = self.shape;
+                let mut vec = Vec::with_capacity(shape.iter().product());
+
+                self.for_each(|$($part),+| vec.push(f($($part),+)));
+
+                Array::init(vec, shape)
+            }
+        }
+    };
+}
+
+zip_apply!(P1);
+zip_apply!(P1, P2);
+zip_apply!(P1, P2, P3);
+zip_apply!(P1, P2, P3, P4);
+zip_apply!(P1, P2, P3, P4, P5);
+zip_apply!(P1, P2, P3, P4, P5, P6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn zip_for_each() {
+        // 2-D arrays:
+        // 0 1 2    1 1 1
+        // 3 4 5    2 2 2
+        let a = Array::arange(0..6).into_shape([2, 3]);
+        let b = Array::init(vec![1, 1, 1, 2, 2, 2], [2, 3]);
+        let mut c = Array::zeros([2, 3]);
+
+        Zip::init(&mut c)
+            .and(&a)
+            .and(&b)
+            .for_each(|c, a, b| *c = a * b);
+
+        assert_eq!(
+            c.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 6, 8, 10]
+        );
+    }
+
+    #[test]
+    fn zip_map_collect() {
+        let a = Array::arange(0..4).into_shape([2, 2]);
+
+        // reversed and transposed views are walked in their logical order:
+        // 3 2    0 2
+        // 1 0    1 3
+        let b = a.slice(&s![..;-1, ..;-1]);
+        let t = a.t();
+
+        let sums = Zip::init(&a)
+            .and(&b)
+            .and(&t)
+            .map_collect(|a, b, t| a + b + t);
+
+        assert_eq!(sums.shape(), &[2, 2]);
+        assert_eq!(
+            sums.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 5, 4, 6]
+        );
+    }
+
+    #[test]
+    fn zip_views() {
+        let mut array = Array::arange(0..6).into_shape([2, 3]);
+        let other = Array::ones([2, 2]);
+
+        let mut view = array.slice_mut(&[0..2, 1..3]);
+        Zip::init(&mut view)
+            .and(&other)
+            .for_each(|elem, one| *elem += one);
+        Zip::init(view).for_each(|elem| *elem *= 10);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 20, 30, 3, 50, 60]
+        );
+    }
+
+    #[test]
+    fn zip_six_parts() {
+        let arrays: Vec<Array<usize, 1>> = (1..6).map(|val| Array::full(val, [3])).collect();
+        let mut out = Array::zeros([3]);
+
+        Zip::init(&mut out)
+            .and(&arrays[0])
+            .and(&arrays[1])
+            .and(&arrays[2])
+            .and(&arrays[3])
+            .and(&arrays[4])
+            .for_each(|out, a, b, c, d, e| *out = a + b + c + d + e);
+
+        assert_eq!(out.flat().copied().collect::<Vec<usize>>(), vec![15; 3]);
+    }
+
+    #[test]
+    fn try_and() {
+        let a: Array<usize, 2> = Array::zeros([2, 3]);
+        let b: Array<usize, 2> = Array::zeros([3, 2]);
+
+        assert!(matches!(
+            Zip::init(&a).try_and(&b),
+            Err(ShapeError::ShapeMismatch {
+                axis: 0,
+                left: 2,
+                right: 3
+            })
+        ));
+    }
+}